@@ -2,34 +2,187 @@ use crate::api::TypedWebSocket;
 use crate::words;
 use anyhow::{anyhow, Error};
 use ferrogallic_shared::api::game::{
-    Canvas, Game, GamePhase, GameReq, GameState, Player, PlayerStatus,
+    validate_custom_words, validate_password, Canvas, ClockSync, CloseReason, Game, GameConfig,
+    GamePhase, GameReq, GameState, JoinReq, PauseReason, Paused, Player, PlayerStatus, Role,
 };
+use ferrogallic_shared::api::lobby::{LobbyInfo, LobbyPhase, LobbySummary};
 use ferrogallic_shared::config::{
-    close_guess_levenshtein, FIRST_CORRECT_BONUS, HEARTBEAT_SECONDS, MINIMUM_GUESS_SCORE,
-    NUMBER_OF_WORDS_TO_CHOOSE, PERFECT_GUESS_SCORE, RX_SHARED_BUFFER, TX_BROADCAST_BUFFER,
-    TX_SELF_DELAYED_BUFFER,
+    close_guess_levenshtein, max_hints, DRAW_ACTIVITY_RESOLUTION_SECONDS, FIRST_CORRECT_BONUS,
+    HEARTBEAT_SECONDS, HINT_PENALTY, IDLE_LOBBY_SECONDS, KICK_BAN_SECONDS,
+    MAX_PASSWORD_BACKOFF_SECONDS, MINIMUM_GUESS_SCORE, MIN_VOTE_KICK_VOTES,
+    NUMBER_OF_WORDS_TO_CHOOSE, PERFECT_GUESS_SCORE, QUICK_PLAY_MAX_PLAYERS,
+    QUICK_PLAY_RESERVATION_SECONDS, ROUND_SUMMARY_SECONDS, RX_SHARED_BUFFER,
+    SHUTDOWN_FLUSH_SECONDS, TX_BROADCAST_BUFFER, TX_SELF_DELAYED_BUFFER, VOTE_KICK_SECONDS,
 };
-use ferrogallic_shared::domain::{Epoch, Guess, Lobby, Lowercase, Nickname, UserId};
+use ferrogallic_shared::domain::{Epoch, Guess, Lobby, Lowercase, Nickname, SessionSecret, UserId};
 use futures::{SinkExt, StreamExt};
 use rand::seq::SliceRandom;
-use rand::thread_rng;
+use rand::{thread_rng, Rng};
+use snapshot::Snapshot;
 use std::cell::Cell;
 use std::collections::btree_map::Entry;
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::convert::Infallible;
 use std::mem;
 use std::ops::Bound;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use strsim::levenshtein;
 use time::OffsetDateTime;
 use tokio::select;
 use tokio::sync::{broadcast, mpsc, oneshot, Mutex};
 use tokio::task::spawn;
-use tokio::time::{interval, Duration, Instant};
+use tokio::time::{interval, sleep, timeout, Duration, Instant};
 use tokio_util::time::DelayQueue;
 
-#[derive(Default)]
+mod snapshot;
+
 pub struct ActiveLobbies {
     tx_lobby: Mutex<HashMap<CaseInsensitiveLobby, mpsc::Sender<GameLoop>>>,
+    data_dir: Option<Arc<Path>>,
+    rehydrate: std::sync::Mutex<HashMap<CaseInsensitiveLobby, Snapshot>>,
+    shutting_down: AtomicBool,
+    // each connection holds a clone, so the receiver closes once they're all gone
+    tx_connected: std::sync::Mutex<Option<mpsc::Sender<Infallible>>>,
+    rx_connected: Mutex<mpsc::Receiver<Infallible>>,
+    listings: std::sync::Mutex<Listings>,
+}
+
+#[derive(Default)]
+struct Listings {
+    public: HashMap<CaseInsensitiveLobby, LobbySummary>,
+    // whether each running lobby is locked
+    running: HashMap<CaseInsensitiveLobby, bool>,
+    // names handed out by quick play, which should start out public once someone joins
+    reserved: HashMap<CaseInsensitiveLobby, Instant>,
+}
+
+impl ActiveLobbies {
+    pub async fn new(data_dir: Option<PathBuf>) -> Self {
+        let rehydrate = match &data_dir {
+            Some(data_dir) => {
+                if let Err(e) = tokio::fs::create_dir_all(data_dir).await {
+                    panic!("Failed to create data dir {}: {}", data_dir.display(), e);
+                }
+                snapshot::load_all(data_dir).await
+            }
+            None => HashMap::new(),
+        };
+        let (tx_connected, rx_connected) = mpsc::channel(1);
+        Self {
+            tx_lobby: Default::default(),
+            data_dir: data_dir.map(Arc::from),
+            rehydrate: std::sync::Mutex::new(rehydrate),
+            shutting_down: AtomicBool::new(false),
+            tx_connected: std::sync::Mutex::new(Some(tx_connected)),
+            rx_connected: Mutex::new(rx_connected),
+            listings: Default::default(),
+        }
+    }
+
+    pub fn public_lobbies(&self) -> Vec<LobbySummary> {
+        let listings = self.listings.lock().unwrap();
+        let mut lobbies = listings.public.values().cloned().collect::<Vec<_>>();
+        lobbies.sort_by(|a, b| b.players.cmp(&a.players).then(a.lobby.cmp(&b.lobby)));
+        lobbies
+    }
+
+    /// Finds the fullest public lobby that still has room, or reserves a new one.
+    pub fn quick_play(&self, generate_name: impl Fn() -> Lobby) -> Lobby {
+        let mut listings = self.listings.lock().unwrap();
+        let joinable = listings
+            .public
+            .values()
+            .filter(|summary| {
+                !summary.locked && summary.players < summary.max_players.min(QUICK_PLAY_MAX_PLAYERS)
+            })
+            .max_by_key(|summary| summary.players);
+        if let Some(summary) = joinable {
+            return summary.lobby.clone();
+        }
+        let now = Instant::now();
+        let expiry = Duration::from_secs(QUICK_PLAY_RESERVATION_SECONDS);
+        listings
+            .reserved
+            .retain(|_, reserved_at| now - *reserved_at < expiry);
+        let lobby = generate_name();
+        listings
+            .reserved
+            .insert(CaseInsensitiveLobby::new(&lobby), now);
+        lobby
+    }
+
+    pub fn lobby_info(&self, lobby: &Lobby) -> LobbyInfo {
+        let key = CaseInsensitiveLobby::new(lobby);
+        if let Some(&locked) = self.listings.lock().unwrap().running.get(&key) {
+            return LobbyInfo { locked };
+        }
+        // ...a missing lobby looks the same as an unlocked one
+        let locked = match self.rehydrate.lock().unwrap().get(&key) {
+            Some(snapshot) => snapshot.password.is_some(),
+            None => false,
+        };
+        LobbyInfo { locked }
+    }
+
+    fn take_reservation(&self, lobby: &Lobby) -> bool {
+        let mut listings = self.listings.lock().unwrap();
+        listings
+            .reserved
+            .remove(&CaseInsensitiveLobby::new(lobby))
+            .is_some()
+    }
+
+    fn list(&self, lobby: &Lobby, locked: Option<bool>, summary: Option<LobbySummary>) {
+        let mut listings = self.listings.lock().unwrap();
+        let key = CaseInsensitiveLobby::new(lobby);
+        match summary {
+            Some(summary) => listings
+                .public
+                .insert(CaseInsensitiveLobby::new(lobby), summary),
+            None => listings.public.remove(&key),
+        };
+        match locked {
+            Some(locked) => listings.running.insert(key, locked),
+            None => listings.running.remove(&key),
+        };
+    }
+
+    /// Stops accepting players, warns everyone, then ends every lobby once `drain` has passed.
+    pub async fn shutdown(&self, drain: Duration) {
+        let tx_lobbies = {
+            let tx_lobby = self.tx_lobby.lock().await;
+            self.shutting_down.store(true, Ordering::SeqCst);
+            tx_lobby.values().cloned().collect::<Vec<_>>()
+        };
+
+        if tx_lobbies.is_empty() {
+            return;
+        }
+
+        log::info!("Notifying {} lobbies of shutdown", tx_lobbies.len());
+        for tx_lobby in &tx_lobbies {
+            let _ = tx_lobby.send(GameLoop::ShutdownNotice(drain)).await;
+        }
+
+        sleep(drain).await;
+
+        for tx_lobby in &tx_lobbies {
+            let (tx_closed, rx_closed) = oneshot::channel();
+            if let Ok(()) = tx_lobby.send(GameLoop::Shutdown(tx_closed)).await {
+                let _ = rx_closed.await;
+            }
+        }
+
+        // give connections a chance to pass on the final message before the process exits
+        drop(self.tx_connected.lock().unwrap().take());
+        let mut rx_connected = self.rx_connected.lock().await;
+        let flush = Duration::from_secs(SHUTDOWN_FLUSH_SECONDS);
+        if let Err(_) = timeout(flush, rx_connected.recv()).await {
+            log::warn!("Timed out waiting for connections to close");
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Hash)]
@@ -45,8 +198,14 @@ pub async fn join_game(
     state: Arc<ActiveLobbies>,
     mut ws: TypedWebSocket<Game>,
 ) -> Result<(), Error> {
-    let (lobby, nick) = match ws.next().await {
-        Some(Ok(GameReq::Join(lobby, nick))) => (lobby, nick),
+    let JoinReq {
+        lobby,
+        nick,
+        session,
+        password,
+        role,
+    } = match ws.next().await {
+        Some(Ok(GameReq::Join(join))) => *join,
         Some(Ok(m)) => return Err(anyhow!("Initial message was not Join: {:?}", m)),
         Some(Err(e)) => return Err(e.context("Failed to receive initial message")),
         None => return Err(anyhow!("WS closed before initial message")),
@@ -54,23 +213,56 @@ pub async fn join_game(
     let user_id = nick.user_id();
     let epoch = Epoch::next();
 
+    let tx_connected = state.tx_connected.lock().unwrap().clone();
+    let _connected = match tx_connected {
+        Some(tx_connected) => tx_connected,
+        None => {
+            ws.send(&Game::Closed(CloseReason::ServerShutdown)).await?;
+            return Ok(());
+        }
+    };
+
     let (tx_lobby, rx_onboard) = loop {
-        let tx_lobby = state
-            .tx_lobby
-            .lock()
-            .await
+        let mut tx_lobbies = state.tx_lobby.lock().await;
+        if state.shutting_down.load(Ordering::SeqCst) {
+            drop(tx_lobbies);
+            log::info!("Player={} Lobby={} rejected: shutting down", nick, lobby);
+            ws.send(&Game::Closed(CloseReason::ServerShutdown)).await?;
+            return Ok(());
+        }
+        let tx_lobby = tx_lobbies
             .entry(CaseInsensitiveLobby::new(&lobby))
             .or_insert_with(|| {
                 let (tx, rx) = mpsc::channel(RX_SHARED_BUFFER);
-                spawn(run_game_loop(lobby.clone(), tx.clone(), rx));
+                let snapshot = state
+                    .rehydrate
+                    .lock()
+                    .unwrap()
+                    .remove(&CaseInsensitiveLobby::new(&lobby));
+                spawn(run_game_loop(
+                    state.clone(),
+                    lobby.clone(),
+                    tx.clone(),
+                    rx,
+                    snapshot,
+                ));
                 tx
             })
             .clone();
+        drop(tx_lobbies);
 
         let (tx_onboard, rx_onboard) = oneshot::channel();
 
         match tx_lobby
-            .send(GameLoop::Connect(user_id, epoch, nick.clone(), tx_onboard))
+            .send(GameLoop::Connect(
+                user_id,
+                epoch,
+                nick.clone(),
+                session,
+                password.clone(),
+                role,
+                tx_onboard,
+            ))
             .await
         {
             Ok(()) => break (tx_lobby, rx_onboard),
@@ -89,7 +281,20 @@ pub async fn join_game(
         let Onboarding {
             mut rx_broadcast,
             messages,
-        } = rx_onboard.await?;
+        } = match rx_onboard.await? {
+            Ok(onboarding) => onboarding,
+            Err(reason) => {
+                log::info!(
+                    "Player={} Lobby={} Epoch={} rejected: {:?}",
+                    nick,
+                    lobby,
+                    epoch,
+                    reason
+                );
+                ws.send(&Game::Closed(reason)).await?;
+                return Ok(());
+            }
+        };
 
         for msg in &messages {
             ws.send(msg).await?;
@@ -120,6 +325,7 @@ pub async fn join_game(
                     }
                 },
                 inbound = ws.next() => match inbound {
+                    Some(Ok(GameReq::Heartbeat(sent))) => ws.send(&heartbeat(Some(sent))).await?,
                     Some(Ok(req)) => match tx_lobby.send(GameLoop::Message(user_id, epoch, req)).await {
                         Ok(()) => {}
                         Err(mpsc::error::SendError(_)) => {
@@ -148,17 +354,42 @@ pub async fn join_game(
     res
 }
 
+/// A heartbeat carrying the server's clock, answering the client's echo if there is one.
+fn heartbeat(echo: Option<OffsetDateTime>) -> Game {
+    Game::Heartbeat(Box::new(ClockSync {
+        server: OffsetDateTime::now_utc(),
+        echo,
+    }))
+}
+
 struct Onboarding {
     rx_broadcast: broadcast::Receiver<Broadcast>,
-    messages: [Game; 3],
+    messages: [Game; 5],
 }
 
 enum GameLoop {
-    Connect(UserId, Epoch<UserId>, Nickname, oneshot::Sender<Onboarding>),
+    Connect(
+        UserId,
+        Epoch<UserId>,
+        Nickname,
+        Option<SessionSecret>,
+        Option<Box<str>>,
+        Role,
+        oneshot::Sender<Result<Onboarding, CloseReason>>,
+    ),
     Message(UserId, Epoch<UserId>, GameReq),
     Disconnect(UserId, Epoch<UserId>),
+    SkipDisconnected(UserId, Epoch<UserId>),
+    RemoveDisconnected(UserId, Epoch<UserId>),
     Heartbeat,
+    ChooseEnd(Epoch<GameState>),
+    Hint(Epoch<GameState>),
     GameEnd(Epoch<GameState>),
+    DrawIdle(Epoch<GameState>),
+    DrawerGrace(Epoch<GameState>),
+    SummaryEnd(Epoch<GameState>),
+    ShutdownNotice(Duration),
+    Shutdown(oneshot::Sender<()>),
 }
 
 #[derive(Debug, Clone)]
@@ -174,13 +405,34 @@ fn broadcast_size() {
     assert_eq!(std::mem::size_of::<Broadcast>(), 48);
 }
 
+#[test]
+fn max_custom_words_fit_in_message() {
+    use ferrogallic_shared::config::{
+        MAX_CUSTOM_WORDS, MAX_WS_MESSAGE_BYTES, VALID_CUSTOM_WORD_LENGTH,
+    };
+    let word = Lowercase::new("x".repeat(*VALID_CUSTOM_WORD_LENGTH.end()));
+    let req = GameReq::SetWords(vec![word; MAX_CUSTOM_WORDS].into());
+    assert!(bincode::serialize(&req).unwrap().len() <= MAX_WS_MESSAGE_BYTES);
+}
+
+#[test]
+fn custom_words_from_the_wire_must_be_lowercase() {
+    let word: Lowercase = bincode::deserialize(&bincode::serialize("Ferris").unwrap()).unwrap();
+    assert!(validate_custom_words(vec![word]).is_err());
+    assert!(validate_custom_words(vec![Lowercase::new("Ferris")]).is_ok());
+}
+
 async fn run_game_loop(
+    state: Arc<ActiveLobbies>,
     lobby: Lobby,
     tx_self: mpsc::Sender<GameLoop>,
     rx: mpsc::Receiver<GameLoop>,
+    snapshot: Option<Snapshot>,
 ) {
     log::info!("Lobby={} starting", lobby);
 
+    let tx_registered = tx_self.clone();
+
     let (tx_self_delayed, mut rx_self_delayed) = mpsc::channel(TX_SELF_DELAYED_BUFFER);
 
     spawn({
@@ -220,32 +472,56 @@ async fn run_game_loop(
         }
     });
 
-    match game_loop(&lobby, tx_self_delayed, rx).await {
-        Ok(()) => log::info!("Lobby={} shutdown, no new connections", lobby),
+    let mut writer = snapshot::Writer::new(state.data_dir.clone());
+
+    let result = game_loop(&state, &lobby, &mut writer, tx_self_delayed, rx, snapshot).await;
+    writer.flush().await;
+
+    let abandoned = match result {
+        Ok(()) => {
+            log::info!("Lobby={} shutdown, no new connections", lobby);
+            false
+        }
         Err(e) => match e {
-            GameLoopError::NoPlayers => {
-                log::info!("Lobby={} shutdown: no players left", lobby);
-            }
             GameLoopError::NoConnectionsDuringStateChange => {
                 log::info!("Lobby={} shutdown: no conns during state change", lobby);
+                true
+            }
+            GameLoopError::Idle => {
+                log::info!("Lobby={} shutdown: all players disconnected", lobby);
+                true
             }
             GameLoopError::DelayRecvGone => {
                 log::error!("Lobby={} shutdown: delay receiver gone", lobby);
+                false
             }
         },
+    };
+
+    state.list(&lobby, None, None);
+    {
+        let mut tx_lobby = state.tx_lobby.lock().await;
+        let key = CaseInsensitiveLobby::new(&lobby);
+        // ...unless a new loop for this lobby has already replaced this one
+        if let Some(tx) = tx_lobby.get(&key) {
+            if tx.same_channel(&tx_registered) {
+                tx_lobby.remove(&key);
+            }
+        }
+    }
+
+    if let (true, Some(data_dir)) = (abandoned, &state.data_dir) {
+        // ...nobody is coming back, so don't resurrect it on the next restart
+        if let Err(e) = snapshot::remove(data_dir, &lobby).await {
+            log::warn!("Lobby={} failed to remove snapshot: {:?}", lobby, e);
+        }
     }
 }
 
 enum GameLoopError {
-    NoPlayers,
     NoConnectionsDuringStateChange,
     DelayRecvGone,
-}
-
-impl From<broadcast::error::SendError<Broadcast>> for GameLoopError {
-    fn from(_: broadcast::error::SendError<Broadcast>) -> Self {
-        Self::NoPlayers
-    }
+    Idle,
 }
 
 impl From<mpsc::error::SendError<(GameLoop, Instant)>> for GameLoopError {
@@ -255,18 +531,63 @@ impl From<mpsc::error::SendError<(GameLoop, Instant)>> for GameLoopError {
 }
 
 async fn game_loop(
+    state: &ActiveLobbies,
     lobby: &Lobby,
+    writer: &mut snapshot::Writer,
     mut tx_self_delayed: mpsc::Sender<(GameLoop, Instant)>,
     mut rx: mpsc::Receiver<GameLoop>,
+    snapshot: Option<Snapshot>,
 ) -> Result<(), GameLoopError> {
+    // broadcasting fails only when nobody is connected, in which case the lobby waits for them to come back
     let (tx, _) = broadcast::channel(TX_BROADCAST_BUFFER);
 
-    let mut players = Invalidate::new(Arc::new(BTreeMap::new()));
-    let mut game_state = Invalidate::new(Arc::new(GameState::default()));
-    let mut canvas_events = Vec::new();
-    let mut guesses = Vec::new();
-
-    guesses.push(Guess::Help);
+    let Snapshot {
+        saved_at: _,
+        lobby: _,
+        players,
+        mut sessions,
+        game_state,
+        mut canvas_events,
+        mut guesses,
+        custom_words,
+        used_words,
+        mut password,
+    } = match snapshot {
+        Some(snapshot) => {
+            log::info!("Lobby={} rehydrating", lobby);
+            snapshot.rehydrate(&mut tx_self_delayed).await?
+        }
+        None => Snapshot {
+            saved_at: OffsetDateTime::now_utc(),
+            lobby: lobby.clone(),
+            players: Default::default(),
+            sessions: Default::default(),
+            game_state: Arc::new(GameState {
+                config: GameConfig {
+                    public: state.take_reservation(lobby),
+                    ..Default::default()
+                },
+                ..Default::default()
+            }),
+            canvas_events: Default::default(),
+            guesses: vec![Guess::Help],
+            custom_words: Default::default(),
+            used_words: Default::default(),
+            password: None,
+        },
+    };
+    let mut players = Invalidate::new(players);
+    let mut game_state = Invalidate::new(game_state);
+    let mut word_pool = WordPool {
+        custom: custom_words,
+        used: used_words,
+    };
+    let mut votes = BTreeMap::<UserId, Vote>::new();
+    let mut bans = BTreeMap::<UserId, Instant>::new();
+    let mut idle_since = None::<Instant>;
+    let mut password_backoffs = BTreeMap::<UserId, PasswordBackoff>::new();
+    // players whose rejected stroke is still coming in, and will be reverted once it ends
+    let mut reverting = BTreeSet::<UserId>::new();
 
     loop {
         let msg = match rx.recv().await {
@@ -274,19 +595,91 @@ async fn game_loop(
             None => return Ok(()),
         };
         match msg {
-            GameLoop::Connect(user_id, epoch, nick, tx_onboard) => {
+            GameLoop::Connect(user_id, epoch, nick, session, attempt, role, tx_onboard) => {
+                let now = Instant::now();
+                bans.retain(|_, until| *until > now);
+                password_backoffs.retain(|_, backoff| !backoff.forgotten(now));
+                if bans.contains_key(&user_id) {
+                    log::info!("Lobby={} Player={} Epoch={} banned", lobby, nick, epoch);
+                    let _ = tx_onboard.send(Err(CloseReason::Banned));
+                    continue;
+                }
+                let returning =
+                    matches!(sessions.get(&user_id), Some(&existing) if session == Some(existing));
+                if !returning {
+                    if players.read().is_empty() && password.is_none() {
+                        if let Some(attempt) = attempt {
+                            if let Err(e) = validate_password(&attempt) {
+                                log::info!("Lobby={} Player={} {}", lobby, nick, e);
+                                let _ = tx_onboard.send(Err(CloseReason::InvalidPassword));
+                                continue;
+                            }
+                            log::info!("Lobby={} Player={} locked on join", lobby, nick);
+                            password = Some(attempt);
+                            Arc::make_mut(game_state.write()).locked = true;
+                        }
+                    } else if let Err(mut reason) =
+                        check_password(password.as_deref(), attempt.as_deref())
+                    {
+                        log::info!(
+                            "Lobby={} Player={} Epoch={} bad password",
+                            lobby,
+                            nick,
+                            epoch
+                        );
+                        if reason == CloseReason::WrongPassword {
+                            let backoff = password_backoffs.entry(user_id).or_default();
+                            if backoff.waiting(now) {
+                                reason = CloseReason::TooManyAttempts;
+                            } else {
+                                backoff.failed(now);
+                            }
+                        }
+                        let _ = tx_onboard.send(Err(reason));
+                        continue;
+                    } else {
+                        password_backoffs.remove(&user_id);
+                    }
+                }
+                let session = match sessions.get(&user_id) {
+                    Some(&existing) if session == Some(existing) => existing,
+                    Some(_) => {
+                        log::info!(
+                            "Lobby={} Player={} Epoch={} bad session",
+                            lobby,
+                            nick,
+                            epoch
+                        );
+                        let _ = tx_onboard.send(Err(CloseReason::NicknameInUse));
+                        continue;
+                    }
+                    None => SessionSecret::new(thread_rng().gen()),
+                };
                 let onboarding = Onboarding {
                     rx_broadcast: tx.subscribe(),
                     messages: [
-                        Game::Game(game_state.read().clone()),
+                        Game::Session(session),
+                        Game::Game(game_state_for(game_state.read(), user_id)),
                         Game::GuessBulk(guesses.clone()),
                         Game::CanvasBulk(canvas_events.clone()),
+                        heartbeat(None),
                     ],
                 };
-                if let Err(_) = tx_onboard.send(onboarding) {
+                if let Err(_) = tx_onboard.send(Ok(onboarding)) {
                     log::warn!("Lobby={} Player={} Epoch={} no onboard", lobby, nick, epoch);
                     continue;
                 }
+                sessions.insert(user_id, session);
+                reverting.remove(&user_id);
+                let seat_free =
+                    seated(players.read()) < usize::from(game_state.read().config.max_players);
+                let role = match role {
+                    Role::Player if !seat_free => Role::Queued,
+                    role => role,
+                };
+                let mut queued = false;
+                let next_joined = players.read().values().map(|p| p.joined + 1).max();
+                let next_joined = next_joined.unwrap_or(0);
                 match Arc::make_mut(players.write()).entry(user_id) {
                     Entry::Vacant(entry) => {
                         log::info!("Lobby={} Player={} Epoch={} join", lobby, nick, epoch);
@@ -295,36 +688,124 @@ async fn game_loop(
                             epoch,
                             status: PlayerStatus::Connected,
                             score: 0,
+                            role,
+                            joined: next_joined,
                         });
+                        queued = role == Role::Queued;
                     }
                     Entry::Occupied(mut entry) => {
                         log::info!("Lobby={} Player={} Epoch={} reconn", lobby, nick, epoch);
                         let player = entry.get_mut();
                         player.epoch = epoch;
                         player.status = PlayerStatus::Connected;
+                        // switching roles mid-game would disturb the rotation
+                        if let GamePhase::WaitingToStart = game_state.read().phase {
+                            match (player.role, role) {
+                                (_, Role::Spectator) | (Role::Spectator, _) => {
+                                    player.role = role;
+                                    queued = role == Role::Queued;
+                                }
+                                // ...already seated or in line
+                                (Role::Player, _) | (Role::Queued, _) => {}
+                            }
+                        }
+                    }
+                }
+                if let Some(Paused {
+                    at,
+                    reason: PauseReason::DrawerDisconnected,
+                }) = game_state.read().paused
+                {
+                    if game_state.read().phase.privileged() == Some(user_id) {
+                        let game_state = Arc::make_mut(game_state.write());
+                        resume_phase(&mut tx_self_delayed, game_state, at).await?;
+                        game_state.paused = None;
+                        (&tx, &mut guesses).send(Guess::System(
+                            format!(
+                                "{} is back, so the turn continues.",
+                                players.read()[&user_id].nick
+                            )
+                            .into(),
+                        ))?;
                     }
                 }
+                if queued {
+                    let queue = &mut Arc::make_mut(game_state.write()).queue;
+                    queue.push(user_id);
+                    let message = format!(
+                        "The lobby is full, so you're #{} in line for a seat.",
+                        queue.len()
+                    );
+                    reply(&tx, user_id, message)?;
+                }
             }
             GameLoop::Message(user_id, epoch, req) => {
                 let player = match players.read().get(&user_id) {
                     Some(player) if player.epoch == epoch => player,
                     _ => {
-                        tx.send(Broadcast::Kill(user_id, epoch))?;
+                        let _ = tx.send(Broadcast::Kill(user_id, epoch));
                         continue;
                     }
                 };
-                let GameState { config, phase } = game_state.read().as_ref();
+                let GameState {
+                    config,
+                    phase,
+                    host,
+                    custom_words: _,
+                    used_words: _,
+                    locked: _,
+                    queue: _,
+                    paused,
+                } = game_state.read().as_ref();
+                let is_host = *host == Some(user_id);
                 match (req, phase) {
-                    (GameReq::Canvas(event), _) => {
-                        (&tx, &mut canvas_events).send(user_id, event)?;
+                    (GameReq::Canvas(event), phase) => {
+                        let may_draw = match phase {
+                            GamePhase::WaitingToStart => true,
+                            GamePhase::ChoosingWords { .. } | GamePhase::RoundSummary { .. } => {
+                                false
+                            }
+                            GamePhase::Drawing { drawing, .. } => *drawing == user_id,
+                        };
+                        if may_draw && paused.is_none() && !reverting.contains(&user_id) {
+                            if let GamePhase::Drawing { last_drawn, .. } = phase {
+                                let now = OffsetDateTime::now_utc();
+                                let resolution =
+                                    time::Duration::seconds(DRAW_ACTIVITY_RESOLUTION_SECONDS);
+                                if now - *last_drawn > resolution {
+                                    if let GamePhase::Drawing { last_drawn, .. } =
+                                        &mut Arc::make_mut(game_state.write()).phase
+                                    {
+                                        *last_drawn = now;
+                                    }
+                                }
+                            }
+                            (&tx, &mut canvas_events).send(user_id, event)?;
+                        } else {
+                            log::debug!("Lobby={} Player={} rejected canvas", lobby, player.nick);
+                            // the sender has already drawn this locally, so undo it once the stroke ends
+                            match event {
+                                Canvas::Line { .. } | Canvas::Fill { .. } => {
+                                    reverting.insert(user_id);
+                                }
+                                Canvas::PushUndo | Canvas::PopUndo | Canvas::Clear => {
+                                    reverting.remove(&user_id);
+                                    (&tx, &mut canvas_events).revert(user_id)?;
+                                }
+                            }
+                        }
                         continue;
                     }
+                    (GameReq::Choose(_), _) | (GameReq::Guess(_), _) if paused.is_some() => {
+                        reply(&tx, user_id, "The game is paused.")?;
+                    }
                     (
                         GameReq::Choose(word),
                         GamePhase::ChoosingWords {
                             round,
                             choosing,
                             words,
+                            ..
                         },
                     ) if *choosing == user_id && words.contains(&word) => {
                         let round = *round;
@@ -343,33 +824,26 @@ async fn game_loop(
                     }
                     (GameReq::Guess(guess), phase) => match phase {
                         GamePhase::WaitingToStart => match guess.as_ref() {
-                            "start" => trans_at_game_start(
-                                &tx,
-                                Arc::make_mut(players.write()),
-                                Arc::make_mut(game_state.write()),
-                                &mut guesses,
-                            )?,
-                            guess if guess.starts_with("rounds ") => {
-                                match guess.trim_start_matches("rounds ").parse() {
-                                    Ok(rounds) => {
-                                        Arc::make_mut(game_state.write()).config.rounds = rounds;
-                                    }
-                                    Err(e) => (&tx, &mut guesses)
-                                        .send(Guess::System(format!("Error: {}.", e).into()))?,
-                                }
+                            "start" if !is_host => {
+                                reply(&tx, user_id, "Only the host can start the game.")?;
                             }
-                            guess if guess.starts_with("seconds ") => {
-                                match guess.trim_start_matches("seconds ").parse() {
-                                    Ok(s) => {
-                                        Arc::make_mut(game_state.write()).config.guess_seconds = s;
-                                    }
-                                    Err(e) => (&tx, &mut guesses)
-                                        .send(Guess::System(format!("Error: {}.", e).into()))?,
-                                }
+                            "start" if first_player(players.read()).is_none() => {
+                                reply(&tx, user_id, "Someone has to join as a player first.")?;
+                            }
+                            "start" => {
+                                trans_at_game_start(
+                                    &tx,
+                                    &mut tx_self_delayed,
+                                    Arc::make_mut(players.write()),
+                                    Arc::make_mut(game_state.write()),
+                                    &mut guesses,
+                                    &mut word_pool,
+                                )
+                                .await?
                             }
                             _ => (&tx, &mut guesses).send(Guess::Message(user_id, guess))?,
                         },
-                        GamePhase::ChoosingWords { .. } => {
+                        GamePhase::ChoosingWords { .. } | GamePhase::RoundSummary { .. } => {
                             (&tx, &mut guesses).send(Guess::Message(user_id, guess))?;
                         }
                         GamePhase::Drawing {
@@ -377,18 +851,36 @@ async fn game_loop(
                             drawing,
                             correct,
                             word,
+                            revealed: _,
                             epoch: _,
                             started,
+                            deadline: _,
+                            next_hint: _,
+                            last_drawn: _,
                         } => {
-                            if *drawing == user_id || correct.contains_key(&user_id) {
+                            if player.role != Role::Player
+                                && levenshtein(&guess, word) <= close_guess_levenshtein(word)
+                            {
+                                // ...so onlookers can't give the word away
+                                reply(&tx, user_id, "Only players can guess; message not sent.")?;
+                            } else if *drawing == user_id
+                                || correct.contains_key(&user_id)
+                                || player.role != Role::Player
+                            {
                                 (&tx, &mut guesses).send(Guess::Message(user_id, guess))?;
                             } else if guess == *word {
                                 let elapsed = OffsetDateTime::now_utc() - *started;
                                 let guess_seconds = config.guess_seconds;
-                                if let GamePhase::Drawing { correct, .. } =
-                                    &mut Arc::make_mut(game_state.write()).phase
+                                if let GamePhase::Drawing {
+                                    correct, revealed, ..
+                                } = &mut Arc::make_mut(game_state.write()).phase
                                 {
-                                    let score = guesser_score(elapsed, guess_seconds, &*correct);
+                                    let score = guesser_score(
+                                        elapsed,
+                                        guess_seconds,
+                                        revealed.len(),
+                                        &*correct,
+                                    );
                                     correct.insert(user_id, score);
                                 }
                                 (&tx, &mut guesses).send(Guess::Correct(user_id))?;
@@ -401,27 +893,220 @@ async fn game_loop(
                                     };
                                 (&tx, &mut guesses).send(Guess::Guess(user_id, guess))?;
                                 if let Some(guess) = was_close {
-                                    tx.send(Broadcast::Only(
+                                    let _ = tx.send(Broadcast::Only(
                                         user_id,
                                         Game::Guess(Guess::CloseGuess(guess)),
-                                    ))?;
+                                    ));
                                 }
                             }
                         }
                     },
+                    (GameReq::Configure(_), _)
+                    | (GameReq::SetWords(_), _)
+                    | (GameReq::ResetWordHistory, _)
+                        if !is_host =>
+                    {
+                        reply(&tx, user_id, "Only the host can change settings.")?;
+                    }
+                    (GameReq::Configure(new_config), GamePhase::WaitingToStart) => {
+                        match new_config.validate() {
+                            Ok(()) => Arc::make_mut(game_state.write()).config = *new_config,
+                            Err(e) => reply(&tx, user_id, format!("Error: {}", e))?,
+                        }
+                    }
+                    (GameReq::SetWords(words), GamePhase::WaitingToStart) => {
+                        match validate_custom_words(words.into_vec()) {
+                            Ok(words) => {
+                                Arc::make_mut(game_state.write()).custom_words = words.len();
+                                word_pool.custom = words;
+                            }
+                            Err(e) => reply(&tx, user_id, format!("Error: {}", e))?,
+                        }
+                    }
+                    (GameReq::ResetWordHistory, GamePhase::WaitingToStart) => {
+                        word_pool.used.clear();
+                        Arc::make_mut(game_state.write()).used_words = 0;
+                    }
+                    (GameReq::Configure(_), _)
+                    | (GameReq::SetWords(_), _)
+                    | (GameReq::ResetWordHistory, _) => {
+                        reply(&tx, user_id, "Settings can't be changed during a game.")?;
+                    }
+                    (GameReq::SetPassword(_), _) if !is_host => {
+                        reply(&tx, user_id, "Only the host can lock the lobby.")?;
+                    }
+                    (GameReq::SetPassword(new_password), _) => match new_password {
+                        Some(new_password) => match validate_password(&new_password) {
+                            Ok(()) => {
+                                password = Some(new_password);
+                                Arc::make_mut(game_state.write()).locked = true;
+                            }
+                            Err(e) => reply(&tx, user_id, format!("Error: {}", e))?,
+                        },
+                        None => {
+                            password = None;
+                            Arc::make_mut(game_state.write()).locked = false;
+                        }
+                    },
+                    (GameReq::SetPaused(_), _) if !is_host => {
+                        reply(&tx, user_id, "Only the host can pause the game.")?;
+                    }
+                    (GameReq::SetPaused(_), GamePhase::WaitingToStart) => {
+                        reply(&tx, user_id, "The game hasn't started yet.")?;
+                    }
+                    (GameReq::SetPaused(pause), _) => match (pause, *paused) {
+                        (true, None) => {
+                            let game_state = Arc::make_mut(game_state.write());
+                            cancel_phase_timers(game_state);
+                            game_state.paused = Some(Paused {
+                                at: OffsetDateTime::now_utc(),
+                                reason: PauseReason::Host,
+                            });
+                            (&tx, &mut guesses).send(Guess::System(
+                                format!("{} paused the game.", player.nick).into(),
+                            ))?;
+                        }
+                        (
+                            true,
+                            Some(Paused {
+                                at,
+                                reason: PauseReason::DrawerDisconnected,
+                            }),
+                        ) => {
+                            // ...take over, so the turn doesn't end while the host has it paused
+                            let game_state = Arc::make_mut(game_state.write());
+                            cancel_phase_timers(game_state);
+                            game_state.paused = Some(Paused {
+                                at,
+                                reason: PauseReason::Host,
+                            });
+                            (&tx, &mut guesses).send(Guess::System(
+                                format!("{} paused the game.", player.nick).into(),
+                            ))?;
+                        }
+                        (
+                            false,
+                            Some(Paused {
+                                at,
+                                reason: PauseReason::Host,
+                            }),
+                        ) => {
+                            let nick = player.nick.clone();
+                            let game_state = Arc::make_mut(game_state.write());
+                            resume_phase(&mut tx_self_delayed, game_state, at).await?;
+                            game_state.paused = None;
+                            (&tx, &mut guesses).send(Guess::System(
+                                format!("{} resumed the game.", nick).into(),
+                            ))?;
+                            if drawer_disconnected(players.read(), &game_state.phase) {
+                                // ...they left while the game was paused
+                                let now = OffsetDateTime::now_utc();
+                                wait_for_drawer(&mut tx_self_delayed, game_state, now).await?;
+                            }
+                        }
+                        (
+                            false,
+                            Some(Paused {
+                                reason: PauseReason::DrawerDisconnected,
+                                ..
+                            }),
+                        ) => {
+                            reply(&tx, user_id, "Waiting for the drawer to reconnect.")?;
+                        }
+                        (
+                            true,
+                            Some(Paused {
+                                reason: PauseReason::Host,
+                                ..
+                            }),
+                        )
+                        | (false, None) => {}
+                    },
+                    (GameReq::VoteKick(_), _) if player.role != Role::Player => {
+                        reply(&tx, user_id, "Only seated players can vote to kick.")?;
+                    }
+                    (GameReq::VoteKick(target), _) if target == user_id => {
+                        reply(&tx, user_id, "You can't vote to kick yourself.")?;
+                    }
+                    (GameReq::VoteKick(target), _) if !players.read().contains_key(&target) => {
+                        reply(&tx, user_id, "That player has already left.")?;
+                    }
+                    (GameReq::VoteKick(target), _)
+                        if votes.get(&target).is_some_and(|vote| {
+                            !vote.expired(Instant::now()) && vote.voters.contains(&user_id)
+                        }) =>
+                    {
+                        reply(&tx, user_id, "You've already voted to kick them.")?;
+                    }
+                    (GameReq::VoteKick(target), _) => {
+                        let now = Instant::now();
+                        let vote = votes.entry(target).or_insert_with(|| Vote::new(now));
+                        if vote.expired(now) {
+                            // ...the last vote against them fizzled out, so start over
+                            *vote = Vote::new(now);
+                        }
+                        vote.voters.insert(user_id);
+                        let eligible_voter = |uid: &UserId| {
+                            *uid != target
+                                && players.read().get(uid).is_some_and(|player| {
+                                    player.status == PlayerStatus::Connected
+                                        && player.role == Role::Player
+                                })
+                        };
+                        let eligible = players
+                            .read()
+                            .keys()
+                            .filter(|uid| eligible_voter(uid))
+                            .count();
+                        let in_favour =
+                            vote.voters.iter().filter(|uid| eligible_voter(uid)).count();
+                        let needed = (eligible / 2 + 1).max(MIN_VOTE_KICK_VOTES);
+                        (&tx, &mut guesses).send(Guess::VoteKick {
+                            voter: user_id,
+                            target,
+                            votes: in_favour as u32,
+                            needed: needed as u32,
+                        })?;
+                        if in_favour >= needed {
+                            votes.remove(&target);
+                            if let Some(kicked) = Arc::make_mut(players.write()).remove(&target) {
+                                log::info!("Lobby={} Player={} kicked", lobby, kicked.nick);
+                                sessions.remove(&target);
+                                bans.insert(target, now + Duration::from_secs(KICK_BAN_SECONDS));
+                                let _ = tx.send(Broadcast::Only(
+                                    target,
+                                    Game::Closed(CloseReason::Kicked),
+                                ));
+                                let _ = tx.send(Broadcast::Kill(target, kicked.epoch));
+                                (&tx, &mut guesses).send(Guess::Kicked(kicked.nick))?;
+                            }
+                        }
+                    }
+                    (GameReq::Remove(..), _) | (GameReq::MakeHost(..), _) if !is_host => {
+                        reply(&tx, user_id, "Only the host can manage players.")?;
+                    }
                     (GameReq::Remove(remove_uid, remove_epoch), _) => {
                         if let Entry::Occupied(entry) =
                             Arc::make_mut(players.write()).entry(remove_uid)
                         {
                             if entry.get().epoch == remove_epoch {
                                 let removed = entry.remove();
+                                sessions.remove(&remove_uid);
                                 log::info!("Lobby={} Player={} removed", lobby, removed.nick);
                             }
                         }
                     }
-                    (req @ GameReq::Choose(..), _) | (req @ GameReq::Join(..), _) => {
+                    (GameReq::MakeHost(new_host), _) => {
+                        if players.read().contains_key(&new_host) {
+                            Arc::make_mut(game_state.write()).host = Some(new_host);
+                            (&tx, &mut guesses).send(Guess::NowHost(new_host))?;
+                        }
+                    }
+                    (req @ GameReq::Choose(..), _)
+                    | (req @ GameReq::Join(..), _)
+                    | (req @ GameReq::Heartbeat(..), _) => {
                         log::warn!("Lobby={} Player={} invalid: {:?}", lobby, player.nick, req);
-                        tx.send(Broadcast::Kill(user_id, epoch))?;
+                        let _ = tx.send(Broadcast::Kill(user_id, epoch));
                     }
                 }
             }
@@ -429,90 +1114,219 @@ async fn game_loop(
                 if let Some(player) = Arc::make_mut(players.write()).get_mut(&user_id) {
                     if player.epoch == epoch {
                         player.status = PlayerStatus::Disconnected;
+                        arm_disconnect_timers(
+                            &mut tx_self_delayed,
+                            &game_state.read().config,
+                            user_id,
+                            epoch,
+                        )
+                        .await?;
+                    }
+                }
+                if game_state.read().paused.is_none()
+                    && game_state.read().phase.privileged() == Some(user_id)
+                    && drawer_disconnected(players.read(), &game_state.read().phase)
+                {
+                    let game_state = Arc::make_mut(game_state.write());
+                    let now = OffsetDateTime::now_utc();
+                    wait_for_drawer(&mut tx_self_delayed, game_state, now).await?;
+                    (&tx, &mut guesses).send(Guess::System(
+                        format!(
+                            "{} disconnected, so the turn is on hold for up to {} seconds.",
+                            players.read()[&user_id].nick,
+                            game_state.config.drawer_grace_seconds
+                        )
+                        .into(),
+                    ))?;
+                }
+            }
+            GameLoop::SkipDisconnected(user_id, epoch) => {
+                let still_gone = players.read().get(&user_id).is_some_and(|player| {
+                    player.epoch == epoch && player.status == PlayerStatus::Disconnected
+                });
+                if still_gone {
+                    if let Some(player) = Arc::make_mut(players.write()).get_mut(&user_id) {
+                        log::info!("Lobby={} Player={} away", lobby, player.nick);
+                        player.status = PlayerStatus::Away;
+                    }
+                }
+            }
+            GameLoop::RemoveDisconnected(user_id, epoch) => {
+                let still_gone = players.read().get(&user_id).is_some_and(|player| {
+                    player.epoch == epoch && player.status != PlayerStatus::Connected
+                });
+                if still_gone {
+                    if let Some(removed) = Arc::make_mut(players.write()).remove(&user_id) {
+                        log::info!("Lobby={} Player={} expired", lobby, removed.nick);
+                        sessions.remove(&user_id);
+                        (&tx, &mut guesses).send(Guess::System(
+                            format!(
+                                "{} was removed after being disconnected too long.",
+                                removed.nick
+                            )
+                            .into(),
+                        ))?;
                     }
                 }
             }
             GameLoop::Heartbeat => {
-                tx.send(Broadcast::Everyone(Game::Heartbeat))?;
+                let now = Instant::now();
+                if players
+                    .read()
+                    .values()
+                    .any(|player| player.status == PlayerStatus::Connected)
+                {
+                    idle_since = None;
+                } else if players.read().is_empty()
+                    || now - *idle_since.get_or_insert(now)
+                        >= Duration::from_secs(IDLE_LOBBY_SECONDS)
+                {
+                    return Err(GameLoopError::Idle);
+                }
+                votes.retain(|_, vote| !vote.expired(now));
+                let _ = tx.send(Broadcast::Everyone(heartbeat(None)));
+                save_snapshot(
+                    lobby,
+                    writer,
+                    players.read(),
+                    &sessions,
+                    game_state.read(),
+                    &canvas_events,
+                    &guesses,
+                    &word_pool,
+                    password.as_deref(),
+                )
+                .await;
             }
-            GameLoop::GameEnd(ended_epoch) => {
-                if let GamePhase::Drawing { epoch, .. } = &game_state.read().phase {
+            GameLoop::ShutdownNotice(drain) => {
+                (&tx, &mut guesses).send(Guess::System(
+                    format!("Server restarting in {} seconds.", drain.as_secs()).into(),
+                ))?;
+            }
+            GameLoop::Shutdown(tx_closed) => {
+                save_snapshot(
+                    lobby,
+                    writer,
+                    players.read(),
+                    &sessions,
+                    game_state.read(),
+                    &canvas_events,
+                    &guesses,
+                    &word_pool,
+                    password.as_deref(),
+                )
+                .await;
+                writer.flush().await;
+                let _ = tx.send(Broadcast::Everyone(Game::Closed(
+                    CloseReason::ServerShutdown,
+                )));
+                let _ = tx_closed.send(());
+                return Ok(());
+            }
+            GameLoop::ChooseEnd(ended_epoch) => {
+                if let GamePhase::ChoosingWords { epoch, .. } = &game_state.read().phase {
                     if *epoch == ended_epoch {
                         let game_state = Arc::make_mut(game_state.write());
-                        if let GamePhase::Drawing {
+                        if let GamePhase::ChoosingWords {
                             round,
-                            drawing,
-                            correct,
-                            word,
+                            choosing,
+                            words,
                             ..
-                        } = &mut game_state.phase
+                        } = &game_state.phase
                         {
-                            (&tx, &mut guesses).send(Guess::TimeExpired(word.clone()))?;
-                            let (round, drawing) = (*round, *drawing);
-                            let correct = mem::take(correct);
-                            trans_at_round_end(
-                                &tx,
-                                Arc::make_mut(players.write()),
-                                game_state,
-                                &mut canvas_events,
-                                &mut guesses,
-                                round,
-                                drawing,
-                                correct,
-                            )?;
+                            let (round, drawing) = (*round, *choosing);
+                            let word = words.choose(&mut thread_rng()).cloned();
+                            match word {
+                                Some(word) => {
+                                    (&tx, &mut guesses).send(Guess::System(
+                                        "Time's up! A word was chosen at random.".into(),
+                                    ))?;
+                                    trans_to_drawing(
+                                        &tx,
+                                        &mut tx_self_delayed,
+                                        game_state,
+                                        &mut canvas_events,
+                                        &mut guesses,
+                                        round,
+                                        drawing,
+                                        word,
+                                    )
+                                    .await?;
+                                    // ...the chooser may have disconnected without being skipped yet
+                                    if drawer_disconnected(players.read(), &game_state.phase) {
+                                        let now = OffsetDateTime::now_utc();
+                                        wait_for_drawer(&mut tx_self_delayed, game_state, now)
+                                            .await?;
+                                        (&tx, &mut guesses).send(Guess::System(
+                                            format!(
+                                                "{} is disconnected, so the turn is on hold for up to {} seconds.",
+                                                players.read()[&drawing].nick,
+                                                game_state.config.drawer_grace_seconds
+                                            )
+                                            .into(),
+                                        ))?;
+                                    }
+                                }
+                                None => {
+                                    trans_at_round_end(
+                                        &tx,
+                                        &mut tx_self_delayed,
+                                        Arc::make_mut(players.write()),
+                                        game_state,
+                                        &mut canvas_events,
+                                        &mut guesses,
+                                        round,
+                                        drawing,
+                                        Default::default(),
+                                        &mut word_pool,
+                                        true,
+                                    )
+                                    .await?;
+                                }
+                            }
                         }
                     }
                 }
             }
-        }
-
-        if players.is_changed() || game_state.is_changed() {
-            match &game_state.read().phase {
-                GamePhase::ChoosingWords { choosing, .. }
-                    if !players.read().contains_key(choosing) =>
-                {
-                    // ...the chooser is gone
-                    let game_state = Arc::make_mut(game_state.write());
-                    if let GamePhase::ChoosingWords {
-                        round, choosing, ..
-                    } = &mut game_state.phase
-                    {
-                        let round = *round;
-                        let drawing = *choosing;
-                        let correct = Default::default();
-                        trans_at_round_end(
-                            &tx,
-                            Arc::make_mut(players.write()),
-                            game_state,
-                            &mut canvas_events,
-                            &mut guesses,
-                            round,
-                            drawing,
-                            correct,
-                        )?;
-                    }
-                }
-                GamePhase::Drawing {
-                    drawing, correct, ..
-                } if players
-                    .read()
-                    .keys()
-                    .all(|uid| drawing == uid || correct.contains_key(uid))
-                    || !players.read().contains_key(drawing) =>
-                {
-                    // ...all players guessed correctly or the drawer is gone
+            GameLoop::DrawerGrace(grace_epoch) => {
+                let still_waiting = match (&game_state.read().paused, &game_state.read().phase) {
+                    (
+                        Some(Paused {
+                            reason: PauseReason::DrawerDisconnected,
+                            ..
+                        }),
+                        GamePhase::Drawing { epoch, .. },
+                    ) => *epoch == grace_epoch,
+                    _ => false,
+                };
+                if still_waiting {
                     let game_state = Arc::make_mut(game_state.write());
+                    game_state.paused = None;
                     if let GamePhase::Drawing {
                         round,
                         drawing,
                         correct,
+                        word,
                         ..
                     } = &mut game_state.phase
                     {
+                        let nick = players
+                            .read()
+                            .get(drawing)
+                            .map(|player| player.nick.clone());
+                        (&tx, &mut guesses).send(Guess::System(
+                            format!(
+                                "{} didn't reconnect in time, so their turn is over. The word was '{}'.",
+                                nick.as_deref().unwrap_or("The drawer"),
+                                word
+                            )
+                            .into(),
+                        ))?;
                         let (round, drawing) = (*round, *drawing);
                         let correct = mem::take(correct);
                         trans_at_round_end(
                             &tx,
+                            &mut tx_self_delayed,
                             Arc::make_mut(players.write()),
                             game_state,
                             &mut canvas_events,
@@ -520,40 +1334,462 @@ async fn game_loop(
                             round,
                             drawing,
                             correct,
-                        )?;
+                            &mut word_pool,
+                            true,
+                        )
+                        .await?;
                     }
                 }
-                _ => {}
             }
-        }
-
-        if let Some(players) = players.reset_if_changed() {
-            tx.send(Broadcast::Everyone(Game::Players(players.clone())))?;
-        }
-        if let Some(state) = game_state.reset_if_changed() {
-            tx.send(Broadcast::Everyone(Game::Game(state.clone())))?;
-        }
-    }
-}
+            GameLoop::SummaryEnd(ended_epoch) => {
+                if let GamePhase::RoundSummary {
+                    round,
+                    drawing,
+                    epoch,
+                    ..
+                } = &game_state.read().phase
+                {
+                    if *epoch == ended_epoch {
+                        let (round, drawing) = (*round, *drawing);
+                        trans_to_next_turn(
+                            &tx,
+                            &mut tx_self_delayed,
+                            Arc::make_mut(players.write()),
+                            Arc::make_mut(game_state.write()),
+                            &mut canvas_events,
+                            &mut guesses,
+                            round,
+                            drawing,
+                            &mut word_pool,
+                        )
+                        .await?;
+                    }
+                }
+            }
+            GameLoop::DrawIdle(idle_epoch) => {
+                let idle_for = match &game_state.read().phase {
+                    GamePhase::Drawing {
+                        epoch, last_drawn, ..
+                    } if *epoch == idle_epoch => Some(OffsetDateTime::now_utc() - *last_drawn),
+                    _ => None,
+                };
+                let limit =
+                    time::Duration::seconds(game_state.read().config.draw_idle_seconds.into());
+                match idle_for {
+                    Some(idle_for) if idle_for < limit => {
+                        // ...they've drawn since this was scheduled, so check again later
+                        let remaining = (limit - idle_for).whole_milliseconds().max(0) as u64;
+                        let idle_check = Instant::now() + Duration::from_millis(remaining);
+                        tx_self_delayed
+                            .send((GameLoop::DrawIdle(idle_epoch), idle_check))
+                            .await?;
+                    }
+                    Some(_) => {
+                        let game_state = Arc::make_mut(game_state.write());
+                        if let GamePhase::Drawing {
+                            round,
+                            drawing,
+                            correct,
+                            word,
+                            ..
+                        } = &mut game_state.phase
+                        {
+                            let nick = players
+                                .read()
+                                .get(drawing)
+                                .map(|player| player.nick.clone());
+                            (&tx, &mut guesses).send(Guess::System(
+                                format!(
+                                    "{} stopped drawing, so their turn is over. The word was '{}'.",
+                                    nick.as_deref().unwrap_or("The drawer"),
+                                    word
+                                )
+                                .into(),
+                            ))?;
+                            let (round, drawing) = (*round, *drawing);
+                            let correct = mem::take(correct);
+                            trans_at_round_end(
+                                &tx,
+                                &mut tx_self_delayed,
+                                Arc::make_mut(players.write()),
+                                game_state,
+                                &mut canvas_events,
+                                &mut guesses,
+                                round,
+                                drawing,
+                                correct,
+                                &mut word_pool,
+                                false,
+                            )
+                            .await?;
+                        }
+                    }
+                    None => {}
+                }
+            }
+            GameLoop::Hint(hint_epoch) => {
+                if let GamePhase::Drawing { epoch, .. } = &game_state.read().phase {
+                    if *epoch == hint_epoch {
+                        reveal_hint(&mut tx_self_delayed, Arc::make_mut(game_state.write()))
+                            .await?;
+                    }
+                }
+            }
+            GameLoop::GameEnd(ended_epoch) => {
+                if let GamePhase::Drawing { epoch, .. } = &game_state.read().phase {
+                    if *epoch == ended_epoch {
+                        let game_state = Arc::make_mut(game_state.write());
+                        if let GamePhase::Drawing {
+                            round,
+                            drawing,
+                            correct,
+                            word,
+                            ..
+                        } = &mut game_state.phase
+                        {
+                            (&tx, &mut guesses).send(Guess::TimeExpired(word.clone()))?;
+                            let (round, drawing) = (*round, *drawing);
+                            let correct = mem::take(correct);
+                            trans_at_round_end(
+                                &tx,
+                                &mut tx_self_delayed,
+                                Arc::make_mut(players.write()),
+                                game_state,
+                                &mut canvas_events,
+                                &mut guesses,
+                                round,
+                                drawing,
+                                correct,
+                                &mut word_pool,
+                                true,
+                            )
+                            .await?;
+                        }
+                    }
+                }
+            }
+        }
+
+        if players.is_changed() || game_state.is_changed() {
+            seat_from_queue(&tx, &mut players, &mut game_state)?;
+        }
+
+        if players.is_changed() {
+            // ...drop votes against or by anyone who left
+            let players = players.read();
+            votes.retain(|target, vote| {
+                vote.voters.retain(|voter| players.contains_key(voter));
+                players.contains_key(target)
+            });
+        }
+
+        if players.is_changed() {
+            let players = players.read();
+            let host = game_state.read().host;
+            let host_present = host
+                .and_then(|host| players.get(&host))
+                .is_some_and(|host| host.status != PlayerStatus::Away);
+            if !host_present {
+                // ...the host is gone, so hand off to whoever joined first
+                let next_host = players
+                    .iter()
+                    .filter(|(_, player)| player.status == PlayerStatus::Connected)
+                    .min_by_key(|(_, player)| player.joined)
+                    .map(|(uid, _)| *uid);
+                if let Some(next_host) = next_host {
+                    Arc::make_mut(game_state.write()).host = Some(next_host);
+                    (&tx, &mut guesses).send(Guess::NowHost(next_host))?;
+                } else if host.is_some_and(|host| !players.contains_key(&host)) {
+                    Arc::make_mut(game_state.write()).host = None;
+                }
+            }
+        }
+
+        // ...while paused, nothing moves on until the host resumes
+        if (players.is_changed() || game_state.is_changed()) && game_state.read().paused.is_none() {
+            match &game_state.read().phase {
+                GamePhase::ChoosingWords { choosing, .. }
+                    if !players.read().get(choosing).is_some_and(in_rotation) =>
+                {
+                    // ...the chooser is gone
+                    let game_state = Arc::make_mut(game_state.write());
+                    if let GamePhase::ChoosingWords {
+                        round, choosing, ..
+                    } = &mut game_state.phase
+                    {
+                        let round = *round;
+                        let drawing = *choosing;
+                        let correct = Default::default();
+                        trans_at_round_end(
+                            &tx,
+                            &mut tx_self_delayed,
+                            Arc::make_mut(players.write()),
+                            game_state,
+                            &mut canvas_events,
+                            &mut guesses,
+                            round,
+                            drawing,
+                            correct,
+                            &mut word_pool,
+                            true,
+                        )
+                        .await?;
+                    }
+                }
+                GamePhase::Drawing {
+                    drawing, correct, ..
+                } if players
+                    .read()
+                    .iter()
+                    .filter(|(_, player)| in_rotation(player))
+                    .all(|(uid, _)| drawing == uid || correct.contains_key(uid))
+                    || !players.read().get(drawing).is_some_and(in_rotation) =>
+                {
+                    // ...all players guessed correctly or the drawer is gone
+                    let game_state = Arc::make_mut(game_state.write());
+                    if let GamePhase::Drawing {
+                        round,
+                        drawing,
+                        correct,
+                        ..
+                    } = &mut game_state.phase
+                    {
+                        let (round, drawing) = (*round, *drawing);
+                        let correct = mem::take(correct);
+                        trans_at_round_end(
+                            &tx,
+                            &mut tx_self_delayed,
+                            Arc::make_mut(players.write()),
+                            game_state,
+                            &mut canvas_events,
+                            &mut guesses,
+                            round,
+                            drawing,
+                            correct,
+                            &mut word_pool,
+                            true,
+                        )
+                        .await?;
+                    }
+                }
+                GamePhase::Drawing {
+                    drawing,
+                    correct,
+                    deadline,
+                    ..
+                } if mostly_guessed(
+                    players.read(),
+                    *drawing,
+                    correct,
+                    game_state.read().config.shorten_after_percent,
+                ) && *deadline
+                    > OffsetDateTime::now_utc()
+                        + time::Duration::seconds(
+                            game_state.read().config.shortened_seconds.into(),
+                        ) =>
+                {
+                    // ...most players guessed correctly, so the rest don't get the full time
+                    shorten_drawing(
+                        &tx,
+                        &mut tx_self_delayed,
+                        Arc::make_mut(game_state.write()),
+                        &mut guesses,
+                    )
+                    .await?;
+                }
+                _ => {}
+            }
+        }
+
+        if players.is_changed() || game_state.is_changed() {
+            let summary = lobby_summary(lobby, players.read(), game_state.read());
+            state.list(lobby, Some(game_state.read().locked), summary);
+        }
+
+        if let Some(players) = players.reset_if_changed() {
+            let _ = tx.send(Broadcast::Everyone(Game::Players(players.clone())));
+        }
+        if let Some(state) = game_state.reset_if_changed() {
+            match state.phase.privileged() {
+                Some(privileged) => {
+                    let _ = tx.send(Broadcast::Only(privileged, Game::Game(state.clone())));
+                    let _ = tx.send(Broadcast::Exclude(
+                        privileged,
+                        Game::Game(Arc::new(state.redacted())),
+                    ));
+                }
+                None => {
+                    let _ = tx.send(Broadcast::Everyone(Game::Game(state.clone())));
+                }
+            }
+        }
+    }
+}
+
+#[tokio::test]
+async fn lobby_outlives_its_last_disconnect() {
+    let (tx, rx) = mpsc::channel(RX_SHARED_BUFFER);
+    let (tx_self_delayed, _rx_self_delayed) = mpsc::channel(TX_SELF_DELAYED_BUFFER);
+    let game_loop = spawn(async move {
+        let state = ActiveLobbies::new(None).await;
+        let mut writer = snapshot::Writer::new(None);
+        let lobby = Lobby::new("lobby");
+        game_loop(&state, &lobby, &mut writer, tx_self_delayed, rx, None).await
+    });
+    let nick = Nickname::new("only");
+    let user_id = nick.user_id();
+    let connect = |epoch, session| {
+        let (tx_onboard, rx_onboard) = oneshot::channel();
+        let connect = GameLoop::Connect(
+            user_id,
+            epoch,
+            nick.clone(),
+            session,
+            None,
+            Role::Player,
+            tx_onboard,
+        );
+        (connect, rx_onboard)
+    };
+
+    let epoch = Epoch::next();
+    let (msg, rx_onboard) = connect(epoch, None);
+    tx.send(msg).await.ok().unwrap();
+    let session = match rx_onboard.await.ok().unwrap().ok().unwrap().messages {
+        [Game::Session(session), ..] => session,
+        _ => panic!("no session"),
+    };
+    tx.send(GameLoop::Disconnect(user_id, epoch))
+        .await
+        .ok()
+        .unwrap();
+    tx.send(GameLoop::Heartbeat).await.ok().unwrap();
+
+    let (msg, rx_onboard) = connect(Epoch::next(), Some(session));
+    tx.send(msg).await.ok().unwrap();
+    assert!(rx_onboard.await.ok().unwrap().is_ok());
+    drop(tx);
+    assert!(matches!(game_loop.await.ok().unwrap(), Ok(())));
+}
+
+async fn save_snapshot(
+    lobby: &Lobby,
+    writer: &mut snapshot::Writer,
+    players: &Arc<BTreeMap<UserId, Player>>,
+    sessions: &BTreeMap<UserId, SessionSecret>,
+    game_state: &Arc<GameState>,
+    canvas_events: &[Canvas],
+    guesses: &[Guess],
+    word_pool: &WordPool,
+    password: Option<&str>,
+) {
+    let snapshot = Snapshot {
+        saved_at: OffsetDateTime::now_utc(),
+        lobby: lobby.clone(),
+        players: players.clone(),
+        sessions: sessions.clone(),
+        game_state: game_state.clone(),
+        canvas_events: canvas_events.to_vec(),
+        guesses: guesses.to_vec(),
+        custom_words: word_pool.custom.clone(),
+        used_words: word_pool.used.clone(),
+        password: password.map(Box::from),
+    };
+    writer.save(snapshot).await;
+}
+
+fn reply(
+    tx: &broadcast::Sender<Broadcast>,
+    user_id: UserId,
+    message: impl Into<Arc<str>>,
+) -> Result<(), GameLoopError> {
+    let _ = tx.send(Broadcast::Only(
+        user_id,
+        Game::Guess(Guess::System(message.into())),
+    ));
+    Ok(())
+}
+
+fn check_password(expected: Option<&str>, attempt: Option<&str>) -> Result<(), CloseReason> {
+    match (expected, attempt) {
+        (None, _) => Ok(()),
+        (Some(_), None) => Err(CloseReason::PasswordRequired),
+        (Some(expected), Some(attempt)) if constant_time_eq(expected, attempt) => Ok(()),
+        (Some(_), Some(_)) => Err(CloseReason::WrongPassword),
+    }
+}
+
+fn constant_time_eq(a: &str, b: &str) -> bool {
+    // ...so timing doesn't reveal how much of a wrong password matched
+    a.len() == b.len()
+        && a.bytes()
+            .zip(b.bytes())
+            .fold(0, |acc, (x, y)| acc | (x ^ y))
+            == 0
+}
+
+fn lobby_summary(
+    lobby: &Lobby,
+    players: &BTreeMap<UserId, Player>,
+    game_state: &GameState,
+) -> Option<LobbySummary> {
+    if !game_state.config.public {
+        return None;
+    }
+    let rounds = game_state.config.rounds;
+    Some(LobbySummary {
+        lobby: lobby.clone(),
+        players: seated(players),
+        max_players: usize::from(game_state.config.max_players),
+        phase: match game_state.phase {
+            GamePhase::WaitingToStart => LobbyPhase::WaitingToStart,
+            GamePhase::ChoosingWords { round, .. }
+            | GamePhase::Drawing { round, .. }
+            | GamePhase::RoundSummary { round, .. } => LobbyPhase::Playing { round, rounds },
+        },
+        locked: game_state.locked,
+    })
+}
+
+fn game_state_for(game_state: &Arc<GameState>, user_id: UserId) -> Arc<GameState> {
+    match game_state.phase.privileged() {
+        Some(privileged) if privileged != user_id => Arc::new(game_state.redacted()),
+        Some(_) | None => game_state.clone(),
+    }
+}
 
 trait CanvasExt {
     fn send(self, user_id: UserId, event: Canvas) -> Result<(), GameLoopError>;
 
     fn clear(self) -> Result<(), GameLoopError>;
+
+    fn revert(self, user_id: UserId) -> Result<(), GameLoopError>;
 }
 
 impl CanvasExt for (&broadcast::Sender<Broadcast>, &mut Vec<Canvas>) {
     fn send(self, user_id: UserId, event: Canvas) -> Result<(), GameLoopError> {
         self.1.push(event);
-        self.0
-            .send(Broadcast::Exclude(user_id, Game::Canvas(event)))?;
+        let _ = self
+            .0
+            .send(Broadcast::Exclude(user_id, Game::Canvas(event)));
         Ok(())
     }
 
     fn clear(self) -> Result<(), GameLoopError> {
         self.1.clear();
-        self.0
-            .send(Broadcast::Everyone(Game::Canvas(Canvas::Clear)))?;
+        let _ = self
+            .0
+            .send(Broadcast::Everyone(Game::Canvas(Canvas::Clear)));
+        Ok(())
+    }
+
+    fn revert(self, user_id: UserId) -> Result<(), GameLoopError> {
+        let _ = self
+            .0
+            .send(Broadcast::Only(user_id, Game::Canvas(Canvas::Clear)));
+        let _ = self
+            .0
+            .send(Broadcast::Only(user_id, Game::CanvasBulk(self.1.clone())));
         Ok(())
     }
 }
@@ -567,55 +1803,163 @@ trait GuessExt {
 impl GuessExt for (&broadcast::Sender<Broadcast>, &mut Vec<Guess>) {
     fn send(self, guess: Guess) -> Result<(), GameLoopError> {
         self.1.push(guess.clone());
-        self.0.send(Broadcast::Everyone(Game::Guess(guess)))?;
+        let _ = self.0.send(Broadcast::Everyone(Game::Guess(guess)));
         Ok(())
     }
 
     fn clear(self) -> Result<(), GameLoopError> {
         self.1.clear();
-        self.0.send(Broadcast::Everyone(Game::ClearGuesses))?;
+        let _ = self.0.send(Broadcast::Everyone(Game::ClearGuesses));
         Ok(())
     }
 }
 
-fn trans_at_game_start(
+async fn trans_at_game_start(
     tx: &broadcast::Sender<Broadcast>,
+    tx_self_delayed: &mut mpsc::Sender<(GameLoop, Instant)>,
     players: &mut BTreeMap<UserId, Player>,
     game_state: &mut GameState,
     guesses: &mut Vec<Guess>,
+    word_pool: &mut WordPool,
 ) -> Result<(), GameLoopError> {
     players.values_mut().for_each(|player| player.score = 0);
     (tx, &mut *guesses).clear()?;
     let round = 1;
-    let next_choosing = match players.keys().next() {
-        Some(choosing) => *choosing,
+    let next_choosing = match first_player(players) {
+        Some(choosing) => choosing,
         None => return Err(GameLoopError::NoConnectionsDuringStateChange),
     };
-    trans_to_choosing(tx, game_state, guesses, round, next_choosing)?;
+    trans_to_choosing(
+        tx,
+        tx_self_delayed,
+        game_state,
+        guesses,
+        round,
+        next_choosing,
+        word_pool,
+    )
+    .await?;
     Ok(())
 }
 
-fn trans_to_choosing(
+async fn trans_to_choosing(
     tx: &broadcast::Sender<Broadcast>,
+    tx_self_delayed: &mut mpsc::Sender<(GameLoop, Instant)>,
     game_state: &mut GameState,
     guesses: &mut Vec<Guess>,
     round: u8,
     next_choosing: UserId,
+    word_pool: &mut WordPool,
 ) -> Result<(), GameLoopError> {
-    let words = words::GAME
-        .choose_multiple(&mut thread_rng(), NUMBER_OF_WORDS_TO_CHOOSE)
-        .copied()
-        .map(Lowercase::new)
-        .collect();
+    let (words, reused) = word_pool.choose(&game_state.config);
+    game_state.used_words = word_pool.used.len();
+    if reused {
+        (tx, &mut *guesses).send(Guess::System(
+            "Every word has been used, so some may repeat.".into(),
+        ))?;
+    }
+    let choose_epoch = Epoch::next();
+    let started = OffsetDateTime::now_utc();
+    let will_end =
+        Instant::now() + Duration::from_secs(u64::from(game_state.config.choose_seconds));
     game_state.phase = GamePhase::ChoosingWords {
         round,
         choosing: next_choosing,
         words,
+        epoch: choose_epoch,
+        started,
     };
     (tx, guesses).send(Guess::NowChoosing(next_choosing))?;
+    tx_self_delayed
+        .send((GameLoop::ChooseEnd(choose_epoch), will_end))
+        .await?;
     Ok(())
 }
 
+#[derive(Default)]
+struct WordPool {
+    custom: Vec<Lowercase>,
+    used: BTreeSet<Lowercase>,
+}
+
+impl WordPool {
+    /// Picks words which haven't been offered yet, and whether any had to be reused.
+    fn choose(&mut self, config: &GameConfig) -> (Arc<[Lowercase]>, bool) {
+        let rng = &mut thread_rng();
+        let mut reused = false;
+
+        let from_custom = (0..NUMBER_OF_WORDS_TO_CHOOSE)
+            .filter(|_| rng.gen_ratio(u32::from(config.custom_words_percent), 100))
+            .count()
+            .min(self.custom.len());
+        let unused_custom = self.custom.iter().filter(|w| !self.used.contains(*w));
+        if unused_custom.count() < from_custom {
+            // ...every custom word has been offered, so start over
+            for word in &self.custom {
+                self.used.remove(word);
+            }
+            reused = true;
+        }
+        let unused_custom = self.custom.iter().filter(|w| !self.used.contains(*w));
+        let custom = unused_custom
+            .collect::<Vec<_>>()
+            .choose_multiple(rng, from_custom)
+            .map(|&word| word.clone())
+            .collect::<Vec<_>>();
+
+        let from_builtin = NUMBER_OF_WORDS_TO_CHOOSE - from_custom;
+        let builtin = config
+            .packs
+            .iter()
+            .flat_map(|&pack| words::pack(pack).iter().copied())
+            .filter(|&word| !custom.iter().any(|custom| custom.as_str() == word))
+            .collect::<Vec<_>>();
+        if builtin.iter().filter(|w| !self.used.contains(**w)).count() < from_builtin {
+            // ...every word in the selected packs has been offered, so start over
+            for &word in &builtin {
+                self.used.remove(word);
+            }
+            reused = true;
+        }
+        let unused_builtin = builtin
+            .into_iter()
+            .filter(|&word| !self.used.contains(word))
+            .collect::<Vec<_>>();
+        let builtin = unused_builtin
+            .choose_multiple(rng, from_builtin)
+            .copied()
+            .map(Lowercase::new);
+
+        let mut words = custom.into_iter().chain(builtin).collect::<Vec<_>>();
+        words.shuffle(rng);
+        self.used.extend(words.iter().cloned());
+        (words.into(), reused)
+    }
+}
+
+#[test]
+fn word_pool_avoids_repeats() {
+    let config = GameConfig {
+        custom_words_percent: 100,
+        ..Default::default()
+    };
+    let mut pool = WordPool {
+        custom: ["a1", "b2", "c3", "d4", "e5", "f6"]
+            .iter()
+            .copied()
+            .map(Lowercase::new)
+            .collect(),
+        used: Default::default(),
+    };
+    let (first, reused) = pool.choose(&config);
+    assert!(!reused);
+    let (second, reused) = pool.choose(&config);
+    assert!(!reused);
+    assert!(first.iter().all(|word| !second.contains(word)));
+    let (_, reused) = pool.choose(&config);
+    assert!(reused);
+}
+
 async fn trans_to_drawing(
     tx: &broadcast::Sender<Broadcast>,
     tx_self_delayed: &mut mpsc::Sender<(GameLoop, Instant)>,
@@ -628,25 +1972,356 @@ async fn trans_to_drawing(
 ) -> Result<(), GameLoopError> {
     let game_epoch = Epoch::next();
     let started = OffsetDateTime::now_utc();
+    let deadline = started + time::Duration::seconds(game_state.config.guess_seconds.into());
     let will_end = Instant::now() + Duration::from_secs(u64::from(game_state.config.guess_seconds));
+    let hints = usize::from(game_state.config.hints).min(max_hints(&word));
+    let mut next_hint = None;
+    arm_next_hint(
+        tx_self_delayed,
+        game_epoch,
+        &mut next_hint,
+        deadline - started,
+        hints,
+    )
+    .await?;
     game_state.phase = GamePhase::Drawing {
         round,
         drawing,
         correct: Default::default(),
         word,
+        revealed: Default::default(),
         epoch: game_epoch,
         started,
+        deadline,
+        next_hint,
+        last_drawn: started,
     };
     (tx, guesses).send(Guess::NowDrawing(drawing))?;
     (tx, canvas_events).clear()?;
     tx_self_delayed
         .send((GameLoop::GameEnd(game_epoch), will_end))
         .await?;
+    let idle_check =
+        Instant::now() + Duration::from_secs(game_state.config.draw_idle_seconds.into());
+    tx_self_delayed
+        .send((GameLoop::DrawIdle(game_epoch), idle_check))
+        .await?;
+    Ok(())
+}
+
+async fn reveal_hint(
+    tx_self_delayed: &mut mpsc::Sender<(GameLoop, Instant)>,
+    game_state: &mut GameState,
+) -> Result<(), GameLoopError> {
+    if let GamePhase::Drawing {
+        word,
+        revealed,
+        epoch,
+        deadline,
+        next_hint,
+        ..
+    } = &mut game_state.phase
+    {
+        let hidden = word
+            .chars()
+            .enumerate()
+            .filter(|&(i, c)| c != ' ' && !revealed.contains(&i))
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+        if let Some(&i) = hidden.choose(&mut thread_rng()) {
+            revealed.insert(i);
+        }
+        let hints_left = usize::from(game_state.config.hints)
+            .min(max_hints(word))
+            .saturating_sub(revealed.len());
+        let time_left = *deadline - OffsetDateTime::now_utc();
+        arm_next_hint(tx_self_delayed, *epoch, next_hint, time_left, hints_left).await?;
+    }
     Ok(())
 }
 
-fn trans_at_round_end(
+/// Moves up the end of the drawing phase.
+async fn shorten_drawing(
     tx: &broadcast::Sender<Broadcast>,
+    tx_self_delayed: &mut mpsc::Sender<(GameLoop, Instant)>,
+    game_state: &mut GameState,
+    guesses: &mut Vec<Guess>,
+) -> Result<(), GameLoopError> {
+    let shortened_seconds = game_state.config.shortened_seconds;
+    let hints = usize::from(game_state.config.hints);
+    if let GamePhase::Drawing {
+        word,
+        revealed,
+        epoch,
+        deadline,
+        next_hint,
+        ..
+    } = &mut game_state.phase
+    {
+        let game_epoch = Epoch::next();
+        *epoch = game_epoch;
+        *deadline = OffsetDateTime::now_utc() + time::Duration::seconds(shortened_seconds.into());
+        let will_end = Instant::now() + Duration::from_secs(shortened_seconds.into());
+        tx_self_delayed
+            .send((GameLoop::GameEnd(game_epoch), will_end))
+            .await?;
+        // ...it reschedules itself if they drew recently
+        tx_self_delayed
+            .send((GameLoop::DrawIdle(game_epoch), Instant::now()))
+            .await?;
+        let hints_left = hints.min(max_hints(word)).saturating_sub(revealed.len());
+        let time_left = time::Duration::seconds(shortened_seconds.into());
+        arm_next_hint(
+            tx_self_delayed,
+            game_epoch,
+            next_hint,
+            time_left,
+            hints_left,
+        )
+        .await?;
+        (tx, guesses).send(Guess::System(
+            format!(
+                "Most players have guessed, so there are only {} seconds left.",
+                shortened_seconds
+            )
+            .into(),
+        ))?;
+    }
+    Ok(())
+}
+
+#[tokio::test]
+async fn shortened_turn_still_reveals_its_hints() {
+    let now = OffsetDateTime::now_utc();
+    let mut game_state = GameState {
+        phase: GamePhase::Drawing {
+            round: 1,
+            drawing: Nickname::new("drawer").user_id(),
+            correct: Default::default(),
+            word: Lowercase::new("ferris"),
+            revealed: Default::default(),
+            epoch: Epoch::next(),
+            started: now,
+            deadline: now + time::Duration::seconds(120),
+            next_hint: None,
+            last_drawn: now,
+        },
+        ..Default::default()
+    };
+    let (tx, _rx) = broadcast::channel(TX_BROADCAST_BUFFER);
+    let (mut tx_self_delayed, mut rx_self_delayed) = mpsc::channel(4);
+
+    shorten_drawing(&tx, &mut tx_self_delayed, &mut game_state, &mut Vec::new())
+        .await
+        .ok()
+        .unwrap();
+    let will_end = match rx_self_delayed.recv().await {
+        Some((GameLoop::GameEnd(_), will_end)) => will_end,
+        _ => panic!("end not re-armed"),
+    };
+    assert!(matches!(
+        rx_self_delayed.recv().await,
+        Some((GameLoop::DrawIdle(_), _))
+    ));
+    for _ in 0..game_state.config.hints {
+        match rx_self_delayed.recv().await {
+            Some((GameLoop::Hint(_), next_hint)) => assert!(next_hint < will_end),
+            _ => panic!("hint not armed"),
+        }
+        reveal_hint(&mut tx_self_delayed, &mut game_state)
+            .await
+            .ok()
+            .unwrap();
+    }
+    assert!(rx_self_delayed.try_recv().is_err());
+}
+
+/// Stops the current phase's timers by moving it to a new epoch.
+fn cancel_phase_timers(game_state: &mut GameState) {
+    match &mut game_state.phase {
+        GamePhase::WaitingToStart => {}
+        GamePhase::ChoosingWords { epoch, .. }
+        | GamePhase::Drawing { epoch, .. }
+        | GamePhase::RoundSummary { epoch, .. } => {
+            *epoch = Epoch::next();
+        }
+    }
+}
+
+/// Freezes the drawing timer until the drawer reconnects or the grace period runs out.
+async fn wait_for_drawer(
+    tx_self_delayed: &mut mpsc::Sender<(GameLoop, Instant)>,
+    game_state: &mut GameState,
+    at: OffsetDateTime,
+) -> Result<(), GameLoopError> {
+    cancel_phase_timers(game_state);
+    game_state.paused = Some(Paused {
+        at,
+        reason: PauseReason::DrawerDisconnected,
+    });
+    if let GamePhase::Drawing { epoch, .. } = &game_state.phase {
+        let grace = Duration::from_secs(game_state.config.drawer_grace_seconds.into());
+        tx_self_delayed
+            .send((GameLoop::DrawerGrace(*epoch), Instant::now() + grace))
+            .await?;
+    }
+    Ok(())
+}
+
+fn drawer_disconnected(players: &BTreeMap<UserId, Player>, phase: &GamePhase) -> bool {
+    match phase {
+        GamePhase::Drawing { drawing, .. } => players
+            .get(drawing)
+            .is_some_and(|player| player.status == PlayerStatus::Disconnected),
+        GamePhase::WaitingToStart
+        | GamePhase::ChoosingWords { .. }
+        | GamePhase::RoundSummary { .. } => false,
+    }
+}
+
+#[tokio::test]
+async fn waiting_for_drawer_freezes_turn_until_resumed() {
+    let frozen_at = OffsetDateTime::now_utc();
+    let mut game_state = GameState {
+        phase: GamePhase::Drawing {
+            round: 1,
+            drawing: Nickname::new("drawer").user_id(),
+            correct: Default::default(),
+            word: Lowercase::new("ferris"),
+            revealed: Default::default(),
+            epoch: Epoch::next(),
+            started: frozen_at,
+            deadline: frozen_at + time::Duration::seconds(60),
+            next_hint: Some(frozen_at + time::Duration::seconds(5)),
+            last_drawn: frozen_at,
+        },
+        ..Default::default()
+    };
+    let (mut tx_self_delayed, mut rx_self_delayed) = mpsc::channel(4);
+
+    wait_for_drawer(&mut tx_self_delayed, &mut game_state, frozen_at)
+        .await
+        .ok()
+        .unwrap();
+    let grace_epoch = match (rx_self_delayed.recv().await, &game_state.phase) {
+        (Some((GameLoop::DrawerGrace(grace), _)), GamePhase::Drawing { epoch, .. }) => {
+            assert!(grace == *epoch);
+            grace
+        }
+        _ => panic!("grace period not armed"),
+    };
+
+    resume_phase(&mut tx_self_delayed, &mut game_state, frozen_at)
+        .await
+        .ok()
+        .unwrap();
+    match rx_self_delayed.recv().await {
+        Some((GameLoop::GameEnd(epoch), _)) => assert!(epoch != grace_epoch),
+        _ => panic!("timer not re-armed"),
+    }
+    assert!(matches!(
+        rx_self_delayed.recv().await,
+        Some((GameLoop::DrawIdle(_), _))
+    ));
+    match rx_self_delayed.recv().await {
+        Some((GameLoop::Hint(_), will_hint)) => {
+            assert!(will_hint <= Instant::now() + Duration::from_secs(5))
+        }
+        _ => panic!("hint not re-armed"),
+    }
+}
+
+/// Re-arms the current phase's timers with the time they had left when they stopped.
+async fn resume_phase(
+    tx_self_delayed: &mut mpsc::Sender<(GameLoop, Instant)>,
+    game_state: &mut GameState,
+    stopped_at: OffsetDateTime,
+) -> Result<(), GameLoopError> {
+    let downtime = OffsetDateTime::now_utc() - stopped_at;
+    let remaining = |started: OffsetDateTime, length: time::Duration| {
+        let elapsed = stopped_at - started;
+        let remaining = length - elapsed;
+        Duration::from_millis(remaining.whole_milliseconds().max(0) as u64)
+    };
+
+    match &mut game_state.phase {
+        GamePhase::WaitingToStart => {}
+        GamePhase::ChoosingWords { epoch, started, .. } => {
+            let choose_seconds = time::Duration::seconds(game_state.config.choose_seconds.into());
+            let will_end = Instant::now() + remaining(*started, choose_seconds);
+            *epoch = Epoch::next();
+            *started += downtime;
+            tx_self_delayed
+                .send((GameLoop::ChooseEnd(*epoch), will_end))
+                .await?;
+        }
+        GamePhase::Drawing {
+            epoch,
+            started,
+            deadline,
+            next_hint,
+            last_drawn,
+            ..
+        } => {
+            let will_end = Instant::now() + remaining(*started, *deadline - *started);
+            let draw_idle_seconds =
+                time::Duration::seconds(game_state.config.draw_idle_seconds.into());
+            let idle_check = Instant::now() + remaining(*last_drawn, draw_idle_seconds);
+            let will_hint = next_hint.map(|at| Instant::now() + remaining(*started, at - *started));
+            *epoch = Epoch::next();
+            *started += downtime;
+            *deadline += downtime;
+            *next_hint = next_hint.map(|at| at + downtime);
+            *last_drawn += downtime;
+            tx_self_delayed
+                .send((GameLoop::GameEnd(*epoch), will_end))
+                .await?;
+            tx_self_delayed
+                .send((GameLoop::DrawIdle(*epoch), idle_check))
+                .await?;
+            if let Some(will_hint) = will_hint {
+                tx_self_delayed
+                    .send((GameLoop::Hint(*epoch), will_hint))
+                    .await?;
+            }
+        }
+        GamePhase::RoundSummary { epoch, started, .. } => {
+            let summary_seconds = time::Duration::seconds(ROUND_SUMMARY_SECONDS.into());
+            let summary_end = Instant::now() + remaining(*started, summary_seconds);
+            *epoch = Epoch::next();
+            *started += downtime;
+            tx_self_delayed
+                .send((GameLoop::SummaryEnd(*epoch), summary_end))
+                .await?;
+        }
+    }
+    Ok(())
+}
+
+async fn arm_next_hint(
+    tx_self_delayed: &mut mpsc::Sender<(GameLoop, Instant)>,
+    epoch: Epoch<GameState>,
+    next_hint: &mut Option<OffsetDateTime>,
+    time_left: time::Duration,
+    hints_left: usize,
+) -> Result<(), GameLoopError> {
+    if hints_left == 0 {
+        *next_hint = None;
+        return Ok(());
+    }
+    // ...so the last one isn't revealed right at the end
+    let interval = time_left / (hints_left as u32 + 1);
+    *next_hint = Some(OffsetDateTime::now_utc() + interval);
+    let interval = Duration::from_millis(interval.whole_milliseconds().max(0) as u64);
+    tx_self_delayed
+        .send((GameLoop::Hint(epoch), Instant::now() + interval))
+        .await?;
+    Ok(())
+}
+
+async fn trans_at_round_end(
+    tx: &broadcast::Sender<Broadcast>,
+    tx_self_delayed: &mut mpsc::Sender<(GameLoop, Instant)>,
     players: &mut BTreeMap<UserId, Player>,
     game_state: &mut GameState,
     canvas_events: &mut Vec<Canvas>,
@@ -654,6 +2329,8 @@ fn trans_at_round_end(
     round: u8,
     drawing: UserId,
     correct: BTreeMap<UserId, u32>,
+    word_pool: &mut WordPool,
+    drawer_earns: bool,
 ) -> Result<(), GameLoopError> {
     for (&user_id, &score) in &correct {
         if let Some(player) = players.get_mut(&user_id) {
@@ -661,24 +2338,77 @@ fn trans_at_round_end(
         }
         (tx, &mut *guesses).send(Guess::EarnedPoints(user_id, score))?;
     }
-    let drawer_score = drawer_score(correct.values().copied(), players.len() as u32);
-    if let Some(drawer) = players.get_mut(&drawing) {
+    let player_count = players
+        .values()
+        .filter(|player| player.role == Role::Player)
+        .count();
+    let drawer_score = drawer_score(correct.values().copied(), player_count as u32);
+    if let (true, Some(drawer)) = (drawer_earns, players.get_mut(&drawing)) {
         drawer.score += drawer_score;
     }
 
+    match &game_state.phase {
+        GamePhase::Drawing { word, .. } => {
+            let summary_epoch = Epoch::next();
+            game_state.phase = GamePhase::RoundSummary {
+                round,
+                drawing,
+                word: word.clone(),
+                correct,
+                drawer_score: if drawer_earns { drawer_score } else { 0 },
+                epoch: summary_epoch,
+                started: OffsetDateTime::now_utc(),
+            };
+            let summary_end = Instant::now() + Duration::from_secs(ROUND_SUMMARY_SECONDS.into());
+            tx_self_delayed
+                .send((GameLoop::SummaryEnd(summary_epoch), summary_end))
+                .await?;
+            Ok(())
+        }
+        // ...nothing was drawn, so there's nothing to sum up
+        GamePhase::WaitingToStart
+        | GamePhase::ChoosingWords { .. }
+        | GamePhase::RoundSummary { .. } => {
+            trans_to_next_turn(
+                tx,
+                tx_self_delayed,
+                players,
+                game_state,
+                canvas_events,
+                guesses,
+                round,
+                drawing,
+                word_pool,
+            )
+            .await
+        }
+    }
+}
+
+async fn trans_to_next_turn(
+    tx: &broadcast::Sender<Broadcast>,
+    tx_self_delayed: &mut mpsc::Sender<(GameLoop, Instant)>,
+    players: &mut BTreeMap<UserId, Player>,
+    game_state: &mut GameState,
+    canvas_events: &mut Vec<Canvas>,
+    guesses: &mut Vec<Guess>,
+    round: u8,
+    drawing: UserId,
+    word_pool: &mut WordPool,
+) -> Result<(), GameLoopError> {
     let player_after_previous = players
         .range((Bound::Excluded(drawing), Bound::Unbounded))
-        .next();
+        .find(|(_, player)| in_rotation(player));
     let next = if let Some(after_prev) = player_after_previous {
         // advancing to next player, same round
         Some((round, *after_prev.0))
     } else if round < game_state.config.rounds {
         // no next player; change to next round
-        let next_choosing = match players.keys().next() {
-            Some(choosing) => *choosing,
-            None => return Err(GameLoopError::NoConnectionsDuringStateChange),
-        };
-        Some((round + 1, next_choosing))
+        if players.is_empty() {
+            return Err(GameLoopError::NoConnectionsDuringStateChange);
+        }
+        // ...unless everyone left is spectating
+        first_player(players).map(|next_choosing| (round + 1, next_choosing))
     } else {
         // end game
         None
@@ -686,12 +2416,91 @@ fn trans_at_round_end(
 
     match next {
         Some((round, next_choosing)) => {
-            trans_to_choosing(tx, game_state, guesses, round, next_choosing)
+            trans_to_choosing(
+                tx,
+                tx_self_delayed,
+                game_state,
+                guesses,
+                round,
+                next_choosing,
+                word_pool,
+            )
+            .await
         }
         None => trans_at_game_end(tx, players, game_state, canvas_events, guesses),
     }
 }
 
+#[tokio::test]
+async fn round_end_shows_summary_before_next_turn() {
+    let drawer = Nickname::new("drawer");
+    let guesser = Nickname::new("guesser");
+    let mut players = vec![
+        test_player("drawer", Role::Player),
+        test_player("guesser", Role::Player),
+    ]
+    .into_iter()
+    .collect::<BTreeMap<_, _>>();
+    let now = OffsetDateTime::now_utc();
+    let mut game_state = GameState {
+        phase: GamePhase::Drawing {
+            round: 1,
+            drawing: drawer.user_id(),
+            correct: Default::default(),
+            word: Lowercase::new("ferris"),
+            revealed: Default::default(),
+            epoch: Epoch::next(),
+            started: now,
+            deadline: now,
+            next_hint: None,
+            last_drawn: now,
+        },
+        ..Default::default()
+    };
+    let correct = std::iter::once((guesser.user_id(), 300)).collect();
+    let (tx, _rx) = broadcast::channel(TX_BROADCAST_BUFFER);
+    let (mut tx_self_delayed, mut rx_self_delayed) = mpsc::channel(4);
+    let mut word_pool = WordPool {
+        custom: Default::default(),
+        used: Default::default(),
+    };
+
+    trans_at_round_end(
+        &tx,
+        &mut tx_self_delayed,
+        &mut players,
+        &mut game_state,
+        &mut Vec::new(),
+        &mut Vec::new(),
+        1,
+        drawer.user_id(),
+        correct,
+        &mut word_pool,
+        true,
+    )
+    .await
+    .ok()
+    .unwrap();
+
+    assert_eq!(players[&guesser.user_id()].score, 300);
+    match (rx_self_delayed.recv().await, &game_state.phase) {
+        (
+            Some((GameLoop::SummaryEnd(ended), _)),
+            GamePhase::RoundSummary {
+                word,
+                correct,
+                epoch,
+                ..
+            },
+        ) => {
+            assert!(ended == *epoch);
+            assert_eq!(word.as_str(), "ferris");
+            assert_eq!(correct[&guesser.user_id()], 300);
+        }
+        _ => panic!("no summary"),
+    }
+}
+
 #[allow(clippy::ptr_arg)]
 fn trans_at_game_end(
     tx: &broadcast::Sender<Broadcast>,
@@ -701,7 +2510,10 @@ fn trans_at_game_end(
     guesses: &mut Vec<Guess>,
 ) -> Result<(), GameLoopError> {
     (tx, &mut *guesses).send(Guess::GameOver)?;
-    for (rank, user_id, player) in Player::rankings(&*players) {
+    let playing = players
+        .iter()
+        .filter(|(_, player)| player.role == Role::Player);
+    for (rank, user_id, player) in Player::rankings(playing) {
         (tx, &mut *guesses).send(Guess::FinalScore {
             rank,
             user_id,
@@ -714,16 +2526,219 @@ fn trans_at_game_end(
     Ok(())
 }
 
+/// Makes each wrong password from a player wait longer before another one is accepted.
+#[derive(Default)]
+struct PasswordBackoff {
+    failures: u32,
+    retry_at: Option<Instant>,
+}
+
+impl PasswordBackoff {
+    fn waiting(&self, now: Instant) -> bool {
+        self.retry_at.is_some_and(|retry_at| now < retry_at)
+    }
+
+    fn forgotten(&self, now: Instant) -> bool {
+        self.retry_at.is_some_and(|retry_at| {
+            now >= retry_at + Duration::from_secs(MAX_PASSWORD_BACKOFF_SECONDS)
+        })
+    }
+
+    fn failed(&mut self, now: Instant) {
+        let seconds = (1 << self.failures.min(6)).min(MAX_PASSWORD_BACKOFF_SECONDS);
+        self.failures += 1;
+        self.retry_at = Some(now + Duration::from_secs(seconds));
+    }
+}
+
+struct Vote {
+    voters: BTreeSet<UserId>,
+    started: Instant,
+}
+
+impl Vote {
+    fn new(started: Instant) -> Self {
+        Self {
+            voters: BTreeSet::new(),
+            started,
+        }
+    }
+
+    fn expired(&self, now: Instant) -> bool {
+        now - self.started > Duration::from_secs(VOTE_KICK_SECONDS)
+    }
+}
+
+/// The number of seats taken, whether or not their players are connected.
+fn seated(players: &BTreeMap<UserId, Player>) -> usize {
+    players
+        .values()
+        .filter(|player| player.role == Role::Player)
+        .count()
+}
+
+/// Moves queued players into any free seats, in the order they arrived.
+fn seat_from_queue(
+    tx: &broadcast::Sender<Broadcast>,
+    players: &mut Invalidate<Arc<BTreeMap<UserId, Player>>>,
+    game_state: &mut Invalidate<Arc<GameState>>,
+) -> Result<(), GameLoopError> {
+    let still_queued = |players: &BTreeMap<UserId, Player>, uid: &UserId| {
+        players
+            .get(uid)
+            .is_some_and(|player| player.role == Role::Queued)
+    };
+    if !game_state
+        .read()
+        .queue
+        .iter()
+        .all(|uid| still_queued(players.read(), uid))
+    {
+        // ...someone in line left or became a spectator
+        let players = players.read();
+        Arc::make_mut(game_state.write())
+            .queue
+            .retain(|uid| still_queued(players, uid));
+    }
+    let max_players = usize::from(game_state.read().config.max_players);
+    while seated(players.read()) < max_players {
+        let next = match game_state.read().queue.first() {
+            Some(&next) => next,
+            None => break,
+        };
+        Arc::make_mut(game_state.write()).queue.remove(0);
+        if let Some(player) = Arc::make_mut(players.write()).get_mut(&next) {
+            player.role = Role::Player;
+        }
+        reply(tx, next, "A seat opened up, so you're now playing.")?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+fn test_player(name: &str, role: Role) -> (UserId, Player) {
+    let nick = Nickname::new(name);
+    let player = Player {
+        nick: nick.clone(),
+        epoch: Epoch::next(),
+        status: PlayerStatus::Connected,
+        score: 0,
+        role,
+        joined: 0,
+    };
+    (nick.user_id(), player)
+}
+
+#[test]
+fn queued_players_take_free_seats_in_order() {
+    let (first, _) = test_player("first", Role::Queued);
+    let (second, _) = test_player("second", Role::Queued);
+    let mut players = Invalidate::new(Arc::new(
+        vec![
+            test_player("seated", Role::Player),
+            test_player("first", Role::Queued),
+            test_player("second", Role::Queued),
+        ]
+        .into_iter()
+        .collect::<BTreeMap<_, _>>(),
+    ));
+    let mut game_state = Invalidate::new(Arc::new(GameState {
+        config: GameConfig {
+            max_players: 2,
+            ..Default::default()
+        },
+        queue: vec![first, second],
+        ..Default::default()
+    }));
+    let (tx, _rx) = broadcast::channel(TX_BROADCAST_BUFFER);
+
+    seat_from_queue(&tx, &mut players, &mut game_state)
+        .ok()
+        .unwrap();
+    assert_eq!(players.read()[&first].role, Role::Player);
+    assert_eq!(players.read()[&second].role, Role::Queued);
+    assert_eq!(game_state.read().queue, vec![second]);
+}
+
+/// The first player in the drawing rotation, skipping spectators and anyone away.
+fn first_player(players: &BTreeMap<UserId, Player>) -> Option<UserId> {
+    players
+        .iter()
+        .find(|(_, player)| in_rotation(player))
+        .map(|(uid, _)| *uid)
+}
+
+fn in_rotation(player: &Player) -> bool {
+    player.role == Role::Player && player.status != PlayerStatus::Away
+}
+
+/// Whether at least `percent` of the players guessing this turn have guessed correctly.
+fn mostly_guessed(
+    players: &BTreeMap<UserId, Player>,
+    drawing: UserId,
+    correct: &BTreeMap<UserId, u32>,
+    percent: u8,
+) -> bool {
+    let (guessers, guessed) = players
+        .iter()
+        .filter(|&(uid, player)| *uid != drawing && in_rotation(player))
+        .fold((0, 0), |(guessers, guessed), (uid, _)| {
+            (
+                guessers + 1,
+                guessed + usize::from(correct.contains_key(uid)),
+            )
+        });
+    guessers > 0 && guessed * 100 >= guessers * usize::from(percent)
+}
+
+#[test]
+fn mostly_guessed_ignores_drawer_and_spectators() {
+    let players = vec![
+        test_player("drawer", Role::Player),
+        test_player("quick", Role::Player),
+        test_player("slow", Role::Player),
+        test_player("watcher", Role::Spectator),
+    ]
+    .into_iter()
+    .collect::<BTreeMap<_, _>>();
+    let drawing = Nickname::new("drawer").user_id();
+    let correct = std::iter::once((Nickname::new("quick").user_id(), 100)).collect();
+
+    assert!(mostly_guessed(&players, drawing, &correct, 50));
+    assert!(!mostly_guessed(&players, drawing, &correct, 75));
+    assert!(!mostly_guessed(&players, drawing, &Default::default(), 50));
+}
+
+async fn arm_disconnect_timers(
+    tx_self_delayed: &mut mpsc::Sender<(GameLoop, Instant)>,
+    config: &GameConfig,
+    user_id: UserId,
+    epoch: Epoch<UserId>,
+) -> Result<(), GameLoopError> {
+    let now = Instant::now();
+    let skip_at = now + Duration::from_secs(config.skip_disconnected_seconds.into());
+    let remove_at = now + Duration::from_secs(config.remove_disconnected_seconds.into());
+    tx_self_delayed
+        .send((GameLoop::SkipDisconnected(user_id, epoch), skip_at))
+        .await?;
+    tx_self_delayed
+        .send((GameLoop::RemoveDisconnected(user_id, epoch), remove_at))
+        .await?;
+    Ok(())
+}
+
 fn guesser_score(
     elapsed: time::Duration,
     guess_seconds: u16,
+    hints: usize,
     existing: &BTreeMap<UserId, u32>,
 ) -> u32 {
     let guess_millis = u32::from(guess_seconds) * 1000;
     let elapsed_millis = elapsed.whole_milliseconds() as u32;
     let time_score = ((guess_millis - elapsed_millis) * PERFECT_GUESS_SCORE)
         .checked_div(guess_millis)
-        .unwrap_or(0);
+        .unwrap_or(0)
+        .saturating_sub(hints as u32 * HINT_PENALTY);
 
     let first_bonus = if existing.is_empty() {
         FIRST_CORRECT_BONUS
@@ -737,7 +2752,7 @@ fn guesser_score(
 fn drawer_score(scores: impl Iterator<Item = u32>, player_count: u32) -> u32 {
     scores
         .sum::<u32>()
-        .checked_div(player_count - 1)
+        .checked_div(player_count.saturating_sub(1))
         .unwrap_or(0)
 }
 
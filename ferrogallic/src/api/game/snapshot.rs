@@ -0,0 +1,294 @@
+use super::{
+    arm_disconnect_timers, cancel_phase_timers, resume_phase, wait_for_drawer,
+    CaseInsensitiveLobby, GameLoop, GameLoopError,
+};
+use anyhow::{Context, Error};
+use ferrogallic_shared::api::game::{
+    Canvas, GamePhase, GameState, PauseReason, Paused, Player, PlayerStatus,
+};
+use ferrogallic_shared::domain::{Epoch, Guess, Lobby, Lowercase, SessionSecret, UserId};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use time::OffsetDateTime;
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::mpsc;
+use tokio::task::{spawn, JoinHandle};
+use tokio::time::Instant;
+
+const EXTENSION: &str = "snapshot";
+
+/// Everything needed to resume a lobby after the server restarts, including its secrets in plain text.
+#[derive(Deserialize, Serialize)]
+pub struct Snapshot {
+    pub saved_at: OffsetDateTime,
+    pub lobby: Lobby,
+    pub players: Arc<BTreeMap<UserId, Player>>,
+    pub sessions: BTreeMap<UserId, SessionSecret>,
+    pub game_state: Arc<GameState>,
+    pub canvas_events: Vec<Canvas>,
+    pub guesses: Vec<Guess>,
+    pub custom_words: Vec<Lowercase>,
+    pub used_words: BTreeSet<Lowercase>,
+    pub password: Option<Box<str>>,
+}
+
+impl Snapshot {
+    /// Prepares a snapshot from a previous process, with new epochs and its timers re-armed.
+    pub async fn rehydrate(
+        mut self,
+        tx_self_delayed: &mut mpsc::Sender<(GameLoop, Instant)>,
+    ) -> Result<Self, GameLoopError> {
+        let saved_at = self.saved_at;
+
+        for player in Arc::make_mut(&mut self.players).values_mut() {
+            player.epoch = Epoch::next();
+            player.status = PlayerStatus::Disconnected;
+        }
+
+        let game_state = Arc::make_mut(&mut self.game_state);
+        match game_state.paused {
+            // ...nobody can draw until the drawer reconnects
+            None if matches!(game_state.phase, GamePhase::Drawing { .. }) => {
+                wait_for_drawer(tx_self_delayed, game_state, saved_at).await?
+            }
+            None => resume_phase(tx_self_delayed, game_state, saved_at).await?,
+            // ...the host will resume it, which re-arms the timers then
+            Some(Paused {
+                reason: PauseReason::Host,
+                ..
+            }) => cancel_phase_timers(game_state),
+            // ...the drawer has to reconnect like everyone else
+            Some(Paused {
+                at,
+                reason: PauseReason::DrawerDisconnected,
+            }) => wait_for_drawer(tx_self_delayed, game_state, at).await?,
+        }
+
+        // nobody is connected yet, so everyone starts counting down until they're skipped
+        for (&user_id, player) in self.players.iter() {
+            arm_disconnect_timers(
+                tx_self_delayed,
+                &self.game_state.config,
+                user_id,
+                player.epoch,
+            )
+            .await?;
+        }
+
+        Ok(self)
+    }
+
+    fn same_as(&self, other: &Self) -> bool {
+        // ...the last snapshot still shares these, so any write since would have cloned them
+        Arc::ptr_eq(&self.players, &other.players)
+            && Arc::ptr_eq(&self.game_state, &other.game_state)
+            && self.canvas_events == other.canvas_events
+            && self.guesses == other.guesses
+    }
+}
+
+/// Saves snapshots for one lobby in the background, one write at a time.
+pub struct Writer {
+    data_dir: Option<Arc<Path>>,
+    last: Option<Snapshot>,
+    pending: Option<JoinHandle<()>>,
+}
+
+impl Writer {
+    pub fn new(data_dir: Option<Arc<Path>>) -> Self {
+        Self {
+            data_dir,
+            last: None,
+            pending: None,
+        }
+    }
+
+    pub async fn save(&mut self, snapshot: Snapshot) {
+        let path = match &self.data_dir {
+            Some(data_dir) => path(data_dir, &snapshot.lobby),
+            None => return,
+        };
+        if self
+            .last
+            .as_ref()
+            .is_some_and(|last| last.same_as(&snapshot))
+        {
+            log::debug!("Lobby={} snapshot unchanged", snapshot.lobby);
+            return;
+        }
+        let bytes = match bincode::serialize(&snapshot) {
+            Ok(bytes) => bytes,
+            Err(e) => {
+                log::warn!(
+                    "Lobby={} failed to serialize snapshot: {:?}",
+                    snapshot.lobby,
+                    e
+                );
+                return;
+            }
+        };
+        self.flush().await;
+        let lobby = snapshot.lobby.clone();
+        self.pending = Some(spawn(async move {
+            match write(&path, bytes).await {
+                Ok(()) => log::debug!("Lobby={} saved snapshot", lobby),
+                Err(e) => log::warn!("Lobby={} failed to save snapshot: {:?}", lobby, e),
+            }
+        }));
+        self.last = Some(snapshot);
+    }
+
+    /// Waits for the last snapshot to hit the disk.
+    pub async fn flush(&mut self) {
+        if let Some(pending) = self.pending.take() {
+            let _ = pending.await;
+        }
+    }
+}
+
+pub async fn load_all(data_dir: &Path) -> HashMap<CaseInsensitiveLobby, Snapshot> {
+    let mut snapshots = HashMap::new();
+    let mut entries = match fs::read_dir(data_dir).await {
+        Ok(entries) => entries,
+        Err(e) => {
+            log::warn!("Failed to read data dir {}: {}", data_dir.display(), e);
+            return snapshots;
+        }
+    };
+    loop {
+        let path = match entries.next_entry().await {
+            Ok(Some(entry)) => entry.path(),
+            Ok(None) => break,
+            Err(e) => {
+                log::warn!("Failed to list data dir {}: {}", data_dir.display(), e);
+                break;
+            }
+        };
+        if path.extension() != Some(OsStr::new(EXTENSION)) {
+            continue;
+        }
+        match load(&path).await {
+            Ok(snapshot) => {
+                log::info!("Lobby={} loaded from {}", snapshot.lobby, path.display());
+                snapshots.insert(CaseInsensitiveLobby::new(&snapshot.lobby), snapshot);
+            }
+            Err(e) => log::warn!("Failed to load {}: {:?}", path.display(), e),
+        }
+    }
+    snapshots
+}
+
+async fn load(path: &Path) -> Result<Snapshot, Error> {
+    let bytes = fs::read(path).await.context("read")?;
+    let snapshot = bincode::deserialize(&bytes).context("deserialize")?;
+    Ok(snapshot)
+}
+
+async fn write(path: &Path, bytes: Vec<u8>) -> Result<(), Error> {
+    // write to a temporary file first, so a crash mid-write doesn't clobber the previous snapshot
+    let tmp_path = path.with_extension("tmp");
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    // ...only the server should read the secrets in it
+    #[cfg(unix)]
+    options.mode(0o600);
+    let mut file = options.open(&tmp_path).await.context("open")?;
+    file.write_all(&bytes).await.context("write")?;
+    file.flush().await.context("flush")?;
+    fs::rename(&tmp_path, path).await.context("rename")?;
+    Ok(())
+}
+
+pub async fn remove(data_dir: &Path, lobby: &Lobby) -> Result<(), Error> {
+    match fs::remove_file(path(data_dir, lobby)).await {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(Error::new(e).context("remove")),
+    }
+}
+
+fn path(data_dir: &Path, lobby: &Lobby) -> PathBuf {
+    // lobby names can contain anything, so hex-encode them to get a safe filename
+    let name = CaseInsensitiveLobby::new(lobby)
+        .0
+        .bytes()
+        .map(|b| format!("{:02x}", b))
+        .collect::<String>();
+    data_dir.join(name).with_extension(EXTENSION)
+}
+
+#[tokio::test]
+async fn rehydrate_round_trip() {
+    use ferrogallic_shared::api::game::Role;
+    use ferrogallic_shared::domain::Nickname;
+
+    let drawer = Nickname::new("drawer");
+    let snapshot = Snapshot {
+        saved_at: OffsetDateTime::now_utc(),
+        lobby: Lobby::new("lobby"),
+        players: Arc::new(
+            std::iter::once((
+                drawer.user_id(),
+                Player {
+                    nick: drawer.clone(),
+                    epoch: Epoch::next(),
+                    status: PlayerStatus::Connected,
+                    score: 123,
+                    role: Role::Player,
+                    joined: 0,
+                },
+            ))
+            .collect(),
+        ),
+        sessions: Default::default(),
+        game_state: Arc::new(GameState {
+            phase: GamePhase::Drawing {
+                round: 1,
+                drawing: drawer.user_id(),
+                correct: Default::default(),
+                word: Lowercase::new("ferris"),
+                revealed: Default::default(),
+                epoch: Epoch::next(),
+                started: OffsetDateTime::now_utc(),
+                deadline: OffsetDateTime::now_utc() + time::Duration::seconds(60),
+                next_hint: None,
+                last_drawn: OffsetDateTime::now_utc(),
+            },
+            ..Default::default()
+        }),
+        canvas_events: vec![Canvas::Clear],
+        guesses: vec![Guess::Help],
+        custom_words: Default::default(),
+        used_words: Default::default(),
+        password: Some("hunter2".into()),
+    };
+    let bytes = bincode::serialize(&snapshot).unwrap();
+    let loaded: Snapshot = bincode::deserialize(&bytes).unwrap();
+
+    let (mut tx_self_delayed, mut rx_self_delayed) = mpsc::channel(8);
+    let rehydrated = loaded.rehydrate(&mut tx_self_delayed).await.ok().unwrap();
+
+    let player = &rehydrated.players[&drawer.user_id()];
+    assert_eq!(player.score, 123);
+    assert_eq!(player.status, PlayerStatus::Disconnected);
+    assert!(player.epoch != snapshot.players[&drawer.user_id()].epoch);
+    assert_eq!(rehydrated.password.as_deref(), Some("hunter2"));
+    match rx_self_delayed.recv().await {
+        Some((GameLoop::DrawerGrace(epoch), _)) => match rehydrated.game_state.phase {
+            GamePhase::Drawing { epoch: new, .. } => assert!(epoch == new),
+            _ => panic!("phase changed"),
+        },
+        _ => panic!("grace period not armed"),
+    }
+    assert!(matches!(
+        rehydrated.game_state.paused,
+        Some(Paused {
+            reason: PauseReason::DrawerDisconnected,
+            ..
+        })
+    ));
+}
@@ -1,16 +1,38 @@
+use crate::api::game::ActiveLobbies;
 use crate::words;
-use ferrogallic_shared::api::lobby::RandomLobbyName;
+use ferrogallic_shared::api::lobby::{LobbyInfo, PublicLobbies, QuickPlay, RandomLobbyName};
 use ferrogallic_shared::domain::Lobby;
 use rand::seq::SliceRandom;
 use rand::thread_rng;
 use std::convert::Infallible;
+use std::sync::Arc;
 
 pub fn random_name(_state: (), _req: ()) -> Result<RandomLobbyName, Infallible> {
+    Ok(RandomLobbyName {
+        lobby: generate_name(),
+    })
+}
+
+pub fn public_lobbies(state: Arc<ActiveLobbies>, _req: ()) -> Result<PublicLobbies, Infallible> {
+    Ok(PublicLobbies {
+        lobbies: state.public_lobbies(),
+    })
+}
+
+pub fn lobby_info(state: Arc<ActiveLobbies>, lobby: Lobby) -> Result<LobbyInfo, Infallible> {
+    Ok(state.lobby_info(&lobby))
+}
+
+pub fn quick_play(state: Arc<ActiveLobbies>, _req: ()) -> Result<QuickPlay, Infallible> {
+    Ok(QuickPlay {
+        lobby: state.quick_play(generate_name),
+    })
+}
+
+fn generate_name() -> Lobby {
     let lobby = words::COMMON_FOR_ROOM_NAMES
         .choose_multiple(&mut thread_rng(), 3)
         .map(|word| word[0..1].to_uppercase() + &word[1..])
         .collect::<String>();
-    Ok(RandomLobbyName {
-        lobby: Lobby::new(lobby),
-    })
+    Lobby::new(lobby)
 }
@@ -12,10 +12,14 @@ mod reply;
 mod server;
 mod words;
 
+use std::time::Duration;
+
 #[tokio::main(flavor = "multi_thread")]
 async fn main() {
     let opt::Options {
         verbose,
+        data_dir,
+        drain_seconds,
         listen_addr,
     } = clap::Parser::parse();
 
@@ -28,5 +32,5 @@ async fn main() {
         })
         .init();
 
-    server::run(listen_addr).await;
+    server::run(listen_addr, data_dir, Duration::from_secs(drain_seconds)).await;
 }
@@ -1,5 +1,6 @@
 use clap::{ArgAction, Parser};
 use std::net::SocketAddr;
+use std::path::PathBuf;
 
 #[derive(Parser, Debug)]
 #[clap(version, about)]
@@ -8,5 +9,13 @@ pub struct Options {
     #[arg(short = 'v', long = "verbose", action = ArgAction::Count, global = true)]
     pub verbose: u8,
 
+    /// directory to save lobby snapshots in, so games survive a restart
+    #[arg(long = "data-dir")]
+    pub data_dir: Option<PathBuf>,
+
+    /// seconds to warn players before shutting down
+    #[arg(long = "drain-seconds", default_value_t = 10)]
+    pub drain_seconds: u64,
+
     pub listen_addr: SocketAddr,
 }
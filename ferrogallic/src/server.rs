@@ -4,11 +4,16 @@ use crate::reply::{bytes, string};
 use ferrogallic_shared::config::MAX_REQUEST_BYTES;
 use ferrogallic_shared::paths;
 use std::net::SocketAddr;
+use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
+use tokio::select;
+use tokio::signal::ctrl_c;
+use tokio::task::spawn;
 use warp::{http, Filter};
 
 #[allow(clippy::let_and_return)]
-pub async fn run(addr: SocketAddr) {
+pub async fn run(addr: SocketAddr, data_dir: Option<PathBuf>, drain: Duration) {
     let static_files = warp::get().and(warp::path("static")).and({
         let favicon = warp::path!("favicon.png").map(|| bytes(files::FAVICON, "image/png"));
         let main_css = warp::path!("main.css").map(|| bytes(files::web::CSS, "text/css"));
@@ -63,18 +68,24 @@ pub async fn run(addr: SocketAddr) {
         )
     });
 
-    let state = Arc::default();
+    let state = Arc::new(api::game::ActiveLobbies::new(data_dir).await);
 
     let api = warp::post()
         .and(warp::path(paths::api::PREFIX))
         .and(warp::body::content_length_limit(MAX_REQUEST_BYTES))
         .and({
             let random_lobby_name = api::endpoint((), api::lobby::random_name);
+            let public_lobbies = api::endpoint(state.clone(), api::lobby::public_lobbies);
+            let quick_play = api::endpoint(state.clone(), api::lobby::quick_play);
+            let lobby_info = api::endpoint(state.clone(), api::lobby::lobby_info);
             random_lobby_name
+                .or(public_lobbies)
+                .or(quick_play)
+                .or(lobby_info)
         });
 
     let ws = warp::path(paths::ws::PREFIX).and({
-        let game = api::websocket(state, api::game::join_game);
+        let game = api::websocket(state.clone(), api::game::join_game);
         game
     });
 
@@ -85,5 +96,38 @@ pub async fn run(addr: SocketAddr) {
         .or(index)
         .with(warp::log(env!("CARGO_PKG_NAME")));
 
-    warp::serve(server).run(addr).await;
+    let (addr, serve) = warp::serve(server).bind_ephemeral(addr);
+    log::info!("Listening on http://{}", addr);
+    // keep serving existing connections while draining
+    spawn(serve);
+
+    shutdown_signal().await;
+    log::info!("Shutting down in {} seconds", drain.as_secs());
+    state.shutdown(drain).await;
+}
+
+async fn shutdown_signal() {
+    #[cfg(unix)]
+    let terminate = async {
+        use tokio::signal::unix::{signal, SignalKind};
+        match signal(SignalKind::terminate()) {
+            Ok(mut terminate) => {
+                terminate.recv().await;
+            }
+            Err(e) => {
+                log::error!("Failed to listen for SIGTERM: {}", e);
+                futures::future::pending().await
+            }
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = futures::future::pending::<()>();
+
+    select! {
+        res = ctrl_c() => if let Err(e) = res {
+            log::error!("Failed to listen for Ctrl-C: {}", e);
+            futures::future::pending().await
+        },
+        () = terminate => {}
+    }
 }
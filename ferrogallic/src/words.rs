@@ -1,5 +1,34 @@
+use ferrogallic_shared::domain::WordPack;
+
+mod actions;
+mod animals;
+mod clothing;
 mod common;
-mod game;
+mod food;
+mod music;
+mod nature;
+mod objects;
+mod people;
+mod places;
+mod sports;
+mod technology;
+mod vehicles;
 
 pub use common::COMMON_FOR_ROOM_NAMES;
-pub use game::GAME;
+
+pub fn pack(pack: WordPack) -> &'static [&'static str] {
+    match pack {
+        WordPack::Animals => animals::ANIMALS,
+        WordPack::Food => food::FOOD,
+        WordPack::People => people::PEOPLE,
+        WordPack::Places => places::PLACES,
+        WordPack::Nature => nature::NATURE,
+        WordPack::Sports => sports::SPORTS,
+        WordPack::Vehicles => vehicles::VEHICLES,
+        WordPack::Music => music::MUSIC,
+        WordPack::Clothing => clothing::CLOTHING,
+        WordPack::Technology => technology::TECHNOLOGY,
+        WordPack::Actions => actions::ACTIONS,
+        WordPack::Objects => objects::OBJECTS,
+    }
+}
@@ -0,0 +1,207 @@
+pub const ACTIONS: &[&str] = &[
+    "addendum",
+    "advertise",
+    "alphabetize",
+    "apathetic",
+    "apathy",
+    "apologize",
+    "ashamed",
+    "ask",
+    "asleep",
+    "attack",
+    "bargain",
+    "big",
+    "bite",
+    "blur",
+    "brainstorm",
+    "brand",
+    "brave",
+    "broken",
+    "buy",
+    "calm",
+    "capitalism",
+    "cartography",
+    "cheat",
+    "check",
+    "clique",
+    "closed",
+    "cold",
+    "comfort",
+    "comfy",
+    "compare",
+    "connect",
+    "connection",
+    "contain",
+    "continuum",
+    "conversation",
+    "correct",
+    "crime",
+    "crisp",
+    "criticize",
+    "cure",
+    "dance",
+    "date",
+    "deep",
+    "defect",
+    "degree",
+    "deliver",
+    "delivery",
+    "devious",
+    "dig",
+    "dirty",
+    "dismantle",
+    "distance",
+    "double",
+    "draw",
+    "drawback",
+    "dream",
+    "drip",
+    "dripping",
+    "economics",
+    "edit",
+    "elope",
+    "empty",
+    "engaged",
+    "enter",
+    "fade",
+    "fancy",
+    "fast",
+    "feudalism",
+    "figment",
+    "first",
+    "fix",
+    "fizz",
+    "flush",
+    "flutter",
+    "forever",
+    "fortnight",
+    "free",
+    "full",
+    "gap",
+    "goodbye",
+    "government",
+    "gratitude",
+    "guarantee",
+    "half",
+    "hang",
+    "happy",
+    "hide",
+    "homework",
+    "honk",
+    "hot",
+    "hug",
+    "idea",
+    "imagine",
+    "implode",
+    "important",
+    "inquisition",
+    "ironic",
+    "jump",
+    "kiss",
+    "kneel",
+    "lag",
+    "laugh",
+    "law",
+    "leak",
+    "learn",
+    "lecture",
+    "lifestyle",
+    "limit",
+    "loyalty",
+    "lucky",
+    "macho",
+    "marry",
+    "melt",
+    "mess",
+    "mistake",
+    "modern",
+    "mooch",
+    "mute",
+    "mysterious",
+    "myth",
+    "nap",
+    "negotiate",
+    "nightmare",
+    "obey",
+    "old",
+    "opaque",
+    "open",
+    "organize",
+    "pain",
+    "peace",
+    "personal",
+    "pinch",
+    "plan",
+    "point",
+    "population",
+    "positive",
+    "pro",
+    "procrastinate",
+    "propose",
+    "push",
+    "quarantine",
+    "quiet",
+    "quit",
+    "random",
+    "read",
+    "recess",
+    "recycle",
+    "regret",
+    "religion",
+    "retail",
+    "retaliate",
+    "reveal",
+    "run",
+    "sad",
+    "save",
+    "scared",
+    "scramble",
+    "scream",
+    "shake",
+    "shallow",
+    "short",
+    "shrink",
+    "sit",
+    "slam",
+    "sleep",
+    "slow",
+    "slump",
+    "snooze",
+    "snore",
+    "snuggle",
+    "soak",
+    "spell",
+    "spill",
+    "spit",
+    "squint",
+    "standing",
+    "start",
+    "stay",
+    "stow",
+    "stutter",
+    "surround",
+    "swoop",
+    "talk",
+    "taxes",
+    "tearful",
+    "testify",
+    "think",
+    "through",
+    "tired",
+    "trapped",
+    "trip",
+    "twist",
+    "type",
+    "unemployed",
+    "unite",
+    "vacation",
+    "vanish",
+    "vision",
+    "wallow",
+    "whatever",
+    "wish",
+    "wobble",
+    "yawn",
+    "zen",
+    "zoom",
+];
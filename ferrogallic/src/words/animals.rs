@@ -0,0 +1,135 @@
+pub const ANIMALS: &[&str] = &[
+    "albatross",
+    "alligator",
+    "anemone",
+    "bald eagle",
+    "bat",
+    "beaver",
+    "bedbug",
+    "beehive",
+    "beluga whale",
+    "bird",
+    "black widow",
+    "blowfish",
+    "boa constrictor",
+    "boa",
+    "buffalo",
+    "bulldog",
+    "bunny",
+    "cat",
+    "catfish",
+    "cattle",
+    "chameleon",
+    "cheetah",
+    "chicken coop",
+    "chicken",
+    "clam",
+    "claw",
+    "clownfish",
+    "cobra",
+    "cobweb",
+    "cocoon",
+    "constrictor",
+    "coop",
+    "cougar",
+    "cow",
+    "coyote",
+    "cricket",
+    "crow",
+    "cub",
+    "daddy longlegs",
+    "deer",
+    "dog",
+    "doghouse",
+    "dolphin",
+    "donkey",
+    "dorsal",
+    "dragonfly",
+    "duck",
+    "eagle",
+    "eel",
+    "elephant",
+    "fang",
+    "fin",
+    "flamingo",
+    "flock",
+    "fox",
+    "frog",
+    "fur",
+    "gallop",
+    "goat",
+    "goldfish",
+    "goose",
+    "growl",
+    "hen",
+    "hermit crab",
+    "hippopotamus",
+    "hiss",
+    "hoof",
+    "horse",
+    "howl",
+    "hummingbird",
+    "insect",
+    "koala",
+    "ladybug",
+    "lion",
+    "lizard",
+    "lobster",
+    "mammoth",
+    "manatee",
+    "migrate",
+    "monkey",
+    "moth",
+    "mouse",
+    "narwhal",
+    "nest",
+    "omnivore",
+    "owl",
+    "panda",
+    "paw",
+    "peck",
+    "pelican",
+    "penguin",
+    "pet",
+    "pigpen",
+    "piranha",
+    "platypus",
+    "poodle",
+    "porcupine",
+    "prey",
+    "puppy",
+    "rat",
+    "reindeer",
+    "rhinoceros",
+    "robin",
+    "salmon",
+    "sea turtle",
+    "seahorse",
+    "seal",
+    "shark",
+    "sheep dog",
+    "sheep",
+    "shrew",
+    "skunk",
+    "snail",
+    "spider web",
+    "spider",
+    "squirrel",
+    "starfish",
+    "stingray",
+    "stork",
+    "swarm",
+    "tadpole",
+    "tail",
+    "three toed sloth",
+    "tiger",
+    "turkey",
+    "tusk",
+    "wag",
+    "water buffalo",
+    "wing",
+    "wolf",
+    "worm",
+    "yak",
+    "zebra",
+];
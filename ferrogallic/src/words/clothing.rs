@@ -0,0 +1,84 @@
+pub const CLOTHING: &[&str] = &[
+    "belt",
+    "bib",
+    "black belt",
+    "blue jeans",
+    "bonnet",
+    "boot",
+    "bowtie",
+    "braid",
+    "button",
+    "cape",
+    "chain mail",
+    "cloak",
+    "clog",
+    "coat",
+    "collar",
+    "corduroy",
+    "costume",
+    "crown",
+    "cuff",
+    "dress shirt",
+    "dress",
+    "earmuffs",
+    "eye patch",
+    "fabric",
+    "fanny pack",
+    "flannel",
+    "fringe",
+    "glasses",
+    "glove",
+    "gown",
+    "hairbrush",
+    "haircut",
+    "hairspray",
+    "hat",
+    "headband",
+    "hem",
+    "jacket",
+    "jeans",
+    "jewelry",
+    "lace",
+    "leather",
+    "lifejacket",
+    "lipstick",
+    "locket",
+    "mask",
+    "mitten",
+    "necktie",
+    "pajamas",
+    "pants",
+    "parka",
+    "plaid",
+    "pocket",
+    "purse",
+    "ring",
+    "robe",
+    "safety goggles",
+    "sandal",
+    "sash",
+    "scarf",
+    "sequins",
+    "shirt",
+    "shoelace",
+    "ski goggles",
+    "skirt",
+    "sleeve",
+    "sock",
+    "stocking",
+    "suit",
+    "sunglasses",
+    "sweater vest",
+    "sweater",
+    "tie",
+    "top hat",
+    "turtleneck",
+    "vest",
+    "wallet",
+    "watch",
+    "wig",
+    "wool",
+    "wristwatch",
+    "yarn",
+    "zipper",
+];
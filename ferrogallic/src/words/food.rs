@@ -0,0 +1,116 @@
+pub const FOOD: &[&str] = &[
+    "apple pie",
+    "applesauce",
+    "avocado",
+    "bagel",
+    "baguette",
+    "bakery",
+    "banana peel",
+    "banana split",
+    "banana",
+    "beer",
+    "bell pepper",
+    "birthday cake",
+    "biscuit",
+    "breakfast",
+    "broccoli",
+    "cafeteria",
+    "cake",
+    "candy",
+    "caviar",
+    "celery",
+    "cheeseburger",
+    "chestnut",
+    "chew",
+    "chocolate chip cookie",
+    "chocolate",
+    "coconut",
+    "cook",
+    "corn",
+    "corndog",
+    "cotton candy",
+    "cracker",
+    "cream",
+    "crumb",
+    "crust",
+    "cucumber",
+    "dinner",
+    "drink",
+    "drumstick",
+    "eat",
+    "fast food",
+    "feast",
+    "flavor",
+    "food",
+    "french fries",
+    "fruit",
+    "ginger",
+    "gingerbread man",
+    "gingerbread",
+    "grape",
+    "gum",
+    "gumball",
+    "honey",
+    "hot dog",
+    "hungry",
+    "jelly",
+    "juice",
+    "lemon",
+    "lime",
+    "loaf",
+    "lollipop",
+    "lunch tray",
+    "lunch",
+    "lunchbox",
+    "macaroni",
+    "marshmallow",
+    "meat",
+    "milk",
+    "muffin",
+    "mushroom",
+    "nut",
+    "onion",
+    "orange",
+    "pancake",
+    "pastry",
+    "pea",
+    "peach",
+    "peanut",
+    "pear",
+    "pepper",
+    "pickle",
+    "picnic",
+    "pie",
+    "pineapple",
+    "pizza sauce",
+    "pizza",
+    "popcorn",
+    "popsicle",
+    "potato",
+    "pretzel",
+    "produce",
+    "pumpkin",
+    "radish",
+    "restaurant",
+    "rice",
+    "rind",
+    "salt and pepper",
+    "salt",
+    "sandwich",
+    "sap",
+    "sip",
+    "soda",
+    "soup",
+    "stew",
+    "strawberry",
+    "sugar",
+    "sushi",
+    "toast",
+    "vanilla",
+    "vegetable",
+    "vegetarian",
+    "waffle",
+    "watermelon",
+    "wedding cake",
+    "yolk",
+];
@@ -1,1659 +0,0 @@
-pub const GAME: &[&str] = &[
-    "accounting",
-    "acne",
-    "acre",
-    "acrobat",
-    "actor",
-    "addendum",
-    "address",
-    "advertise",
-    "aircraft carrier",
-    "aircraft",
-    "airport security",
-    "airport",
-    "aisle",
-    "alarm clock",
-    "albatross",
-    "alligator",
-    "alphabetize",
-    "ambulance",
-    "america",
-    "amusement park",
-    "anemone",
-    "angel",
-    "ankle",
-    "anvil",
-    "apathetic",
-    "apathy",
-    "apologize",
-    "applause",
-    "apple pie",
-    "applesauce",
-    "application",
-    "aquarium",
-    "arcade",
-    "archaeologist",
-    "aristocrat",
-    "arm",
-    "armada",
-    "art gallery",
-    "art",
-    "artist",
-    "ashamed",
-    "ask",
-    "asleep",
-    "astronaut",
-    "athlete",
-    "atlantis",
-    "atlas",
-    "attack",
-    "attic",
-    "aunt",
-    "avocado",
-    "baby",
-    "babysitter",
-    "back flip",
-    "back",
-    "backbone",
-    "bacteria",
-    "bag",
-    "bagel",
-    "baggage",
-    "bagpipe",
-    "baguette",
-    "baker",
-    "bakery",
-    "balance beam",
-    "bald eagle",
-    "bald",
-    "balloon",
-    "banana peel",
-    "banana split",
-    "banana",
-    "banister",
-    "banjo",
-    "barber",
-    "barbershop",
-    "bargain",
-    "barn",
-    "barrel",
-    "base",
-    "baseball",
-    "baseboards",
-    "basket",
-    "basketball",
-    "bat",
-    "bathroom scale",
-    "bathtub",
-    "batteries",
-    "battery",
-    "beach",
-    "beanstalk",
-    "beaver",
-    "bedbug",
-    "beehive",
-    "beer",
-    "beethoven",
-    "bell pepper",
-    "bell",
-    "belt",
-    "beluga whale",
-    "best friend",
-    "bib",
-    "bicycle",
-    "big",
-    "bike",
-    "billboard",
-    "bird",
-    "birthday cake",
-    "birthday",
-    "biscuit",
-    "bite",
-    "black belt",
-    "black hole",
-    "black widow",
-    "blacksmith",
-    "blanket",
-    "bleach",
-    "blimp",
-    "blizzard",
-    "blossom",
-    "blowfish",
-    "blue jeans",
-    "blueprint",
-    "blur",
-    "blush",
-    "boa constrictor",
-    "boa",
-    "boat",
-    "bob",
-    "bobsled",
-    "body",
-    "bomb",
-    "bonnet",
-    "book",
-    "bookend",
-    "bookstore",
-    "boot",
-    "booth",
-    "border",
-    "bottle",
-    "boulevard",
-    "bowtie",
-    "box",
-    "boxing",
-    "boy",
-    "braid",
-    "brain",
-    "brainstorm",
-    "brand",
-    "brave",
-    "breakfast",
-    "brick",
-    "bride",
-    "bridge",
-    "broccoli",
-    "broken",
-    "broom",
-    "bruise",
-    "brunette",
-    "brush",
-    "bubble",
-    "bucket",
-    "buddy",
-    "buffalo",
-    "bug spray",
-    "buggy",
-    "bulb",
-    "bulldog",
-    "bunny",
-    "bus",
-    "bushes",
-    "butcher",
-    "button",
-    "buy",
-    "cabin",
-    "cable car",
-    "cactus",
-    "cafeteria",
-    "cage",
-    "cake",
-    "calculator",
-    "calendar",
-    "calm",
-    "camera",
-    "campfire",
-    "campsite",
-    "can",
-    "canada",
-    "candle",
-    "candy",
-    "cannon",
-    "canoe",
-    "cape",
-    "capitalism",
-    "captain",
-    "car dealership",
-    "car",
-    "carat",
-    "cardboard",
-    "cargo",
-    "carnival",
-    "carousel",
-    "carpenter",
-    "carpet",
-    "cartography",
-    "cartoon",
-    "cash",
-    "cast",
-    "castle",
-    "cat",
-    "catalog",
-    "catfish",
-    "cattle",
-    "cave",
-    "caviar",
-    "ceiling fan",
-    "ceiling",
-    "celery",
-    "cell phone charger",
-    "cell phone",
-    "cell",
-    "cellar",
-    "cello",
-    "cemetery",
-    "century",
-    "chain mail",
-    "chain",
-    "chair",
-    "chairman",
-    "chalk",
-    "chameleon",
-    "champion",
-    "character",
-    "charger",
-    "chariot racing",
-    "chariot",
-    "chart",
-    "cheat",
-    "check",
-    "cheek",
-    "cheerleader",
-    "cheeseburger",
-    "cheetah",
-    "chef",
-    "chemical",
-    "cherub",
-    "chess",
-    "chest",
-    "chestnut",
-    "chew",
-    "chicken coop",
-    "chicken",
-    "children",
-    "chime",
-    "chimney",
-    "chin",
-    "china",
-    "chip",
-    "chisel",
-    "chocolate chip cookie",
-    "chocolate",
-    "church",
-    "circus",
-    "city",
-    "clam",
-    "clamp",
-    "class",
-    "classroom",
-    "claw",
-    "clay",
-    "cliff diving",
-    "cliff",
-    "clique",
-    "cloak",
-    "clockwork",
-    "clog",
-    "closed",
-    "clown",
-    "clownfish",
-    "clue",
-    "coach",
-    "coal",
-    "coast",
-    "coaster",
-    "coastline",
-    "coat",
-    "cobra",
-    "cobweb",
-    "cockpit",
-    "coconut",
-    "cocoon",
-    "cog",
-    "coil",
-    "coin",
-    "cold",
-    "collar",
-    "college",
-    "comfort",
-    "comfy",
-    "commercial",
-    "company",
-    "compare",
-    "compass",
-    "competition",
-    "computer",
-    "concession stand",
-    "cone",
-    "connect",
-    "connection",
-    "constellation",
-    "constrictor",
-    "contain",
-    "continuum",
-    "conversation",
-    "conveyor belt",
-    "cook",
-    "coop",
-    "cord",
-    "corduroy",
-    "cork",
-    "corn",
-    "corndog",
-    "corner",
-    "correct",
-    "costume",
-    "cot",
-    "cotton candy",
-    "cougar",
-    "cough",
-    "country",
-    "cousin",
-    "cover",
-    "cow",
-    "cowboy",
-    "coworker",
-    "coyote",
-    "crack",
-    "cracker",
-    "crane",
-    "crate",
-    "crater",
-    "crayon",
-    "cream",
-    "crib",
-    "cricket",
-    "crime",
-    "crisp",
-    "criticize",
-    "crop duster",
-    "crow",
-    "crow's nest",
-    "crown",
-    "cruise ship",
-    "cruise",
-    "crumb",
-    "crust",
-    "cub",
-    "cubicle",
-    "cuckoo clock",
-    "cucumber",
-    "cuff",
-    "curb",
-    "cure",
-    "curtain",
-    "curtains",
-    "curve",
-    "cushion",
-    "customer",
-    "cuticle",
-    "czar",
-    "dad",
-    "daddy longlegs",
-    "dance",
-    "darkness",
-    "dart",
-    "darts",
-    "dashboard",
-    "date",
-    "dawn",
-    "day",
-    "dead end",
-    "deep",
-    "deer",
-    "defect",
-    "degree",
-    "deliver",
-    "delivery",
-    "density",
-    "dent",
-    "dentist",
-    "desert",
-    "desk",
-    "detective",
-    "devious",
-    "dew",
-    "diagonal",
-    "dictionary",
-    "dig",
-    "dimple",
-    "dinner",
-    "dirt",
-    "dirty",
-    "disc jockey",
-    "dismantle",
-    "distance",
-    "ditch",
-    "diver",
-    "dizzy",
-    "dock",
-    "doctor",
-    "dodgeball",
-    "dog leash",
-    "dog",
-    "doghouse",
-    "doll",
-    "dollar",
-    "dolphin",
-    "dominoes",
-    "donkey",
-    "door",
-    "doorknob",
-    "doormat",
-    "dorsal",
-    "dot",
-    "double",
-    "download",
-    "downpour",
-    "dragon",
-    "dragonfly",
-    "drain",
-    "draw",
-    "drawback",
-    "drawer",
-    "dream",
-    "dress shirt",
-    "dress",
-    "drill bit",
-    "drill",
-    "drink",
-    "drip",
-    "dripping",
-    "driveway",
-    "drought",
-    "drugstore",
-    "drums",
-    "drumstick",
-    "dryer",
-    "duck",
-    "dump truck",
-    "dump",
-    "dunk",
-    "dust bunny",
-    "dust",
-    "dustpan",
-    "eagle",
-    "ear",
-    "earache",
-    "earmuffs",
-    "earthquake",
-    "easel",
-    "east",
-    "eat",
-    "ebony",
-    "eclipse",
-    "economics",
-    "edge",
-    "edit",
-    "eel",
-    "eighteen wheeler",
-    "elbow",
-    "electrical outlet",
-    "electricity",
-    "elephant",
-    "elevator",
-    "elf",
-    "elm",
-    "elope",
-    "empty",
-    "end zone",
-    "engaged",
-    "engine",
-    "england",
-    "enter",
-    "envelope",
-    "equator",
-    "eraser",
-    "ergonomic",
-    "escalator",
-    "eureka",
-    "europe",
-    "evolution",
-    "exercise",
-    "expert",
-    "extension cord",
-    "extension",
-    "eye patch",
-    "eyebrow",
-    "fabric",
-    "face",
-    "factory",
-    "fade",
-    "fairies",
-    "family",
-    "fan",
-    "fancy",
-    "fang",
-    "fanny pack",
-    "farm",
-    "farmer",
-    "fast food",
-    "fast",
-    "faucet",
-    "fax",
-    "feast",
-    "fence",
-    "fern",
-    "ferry",
-    "feudalism",
-    "fiance",
-    "fiddle",
-    "field",
-    "figment",
-    "fin",
-    "finger",
-    "fire hydrant",
-    "fire",
-    "firefighter",
-    "fireman pole",
-    "fireside",
-    "first class",
-    "first",
-    "fishing pole",
-    "fishing",
-    "fist",
-    "fix",
-    "fizz",
-    "flagpole",
-    "flamingo",
-    "flannel",
-    "flashlight",
-    "flavor",
-    "flock",
-    "flood",
-    "floor",
-    "florist",
-    "flotsam",
-    "flower",
-    "flu",
-    "flush",
-    "flute",
-    "flutter",
-    "foam",
-    "fog",
-    "foil",
-    "food",
-    "football",
-    "forehead",
-    "forest",
-    "forever",
-    "fork",
-    "fortnight",
-    "fortress",
-    "fox",
-    "frame",
-    "france",
-    "freckle",
-    "free",
-    "freight",
-    "french fries",
-    "fresh water",
-    "freshman",
-    "fringe",
-    "frog",
-    "front porch",
-    "front",
-    "frost",
-    "frown",
-    "fruit",
-    "frying pan",
-    "full moon",
-    "full",
-    "fungus",
-    "fur",
-    "gallon",
-    "gallop",
-    "game",
-    "gap",
-    "garage",
-    "garbage truck",
-    "garbage",
-    "garden hose",
-    "garden",
-    "gas station",
-    "gasoline",
-    "gate",
-    "gem",
-    "geologist",
-    "germ",
-    "geyser",
-    "giant",
-    "gift",
-    "ginger",
-    "gingerbread man",
-    "gingerbread",
-    "girl",
-    "girlfriend",
-    "glass",
-    "glasses",
-    "glitter",
-    "globe",
-    "glove",
-    "glue gun",
-    "glue stick",
-    "glue",
-    "goalkeeper",
-    "goat",
-    "goblin",
-    "gold medal",
-    "gold",
-    "goldfish",
-    "golf cart",
-    "golf",
-    "goodbye",
-    "goose",
-    "government",
-    "gown",
-    "grandma",
-    "grandpa",
-    "grape",
-    "graph",
-    "grass",
-    "grasslands",
-    "gratitude",
-    "gravity",
-    "gray",
-    "green",
-    "grill",
-    "grocery store",
-    "groom",
-    "growl",
-    "guarantee",
-    "guitar",
-    "gum",
-    "gumball",
-    "hail",
-    "hair dryer",
-    "hair",
-    "hairbrush",
-    "haircut",
-    "hairspray",
-    "half",
-    "hammer",
-    "hand soap",
-    "handle",
-    "handwriting",
-    "hang glider",
-    "hang",
-    "happy",
-    "harmonica",
-    "harp",
-    "hat",
-    "hatch",
-    "headache",
-    "headband",
-    "heart",
-    "heater",
-    "hedge",
-    "heel",
-    "helicopter",
-    "helium",
-    "hem",
-    "hen",
-    "hermit crab",
-    "hero",
-    "hide",
-    "highway",
-    "hill",
-    "hip",
-    "hippopotamus",
-    "hipster",
-    "hiss",
-    "hockey",
-    "hole",
-    "homeless",
-    "homework",
-    "honey",
-    "honk",
-    "hoof",
-    "hook",
-    "hoop",
-    "hopscotch",
-    "horn",
-    "horse",
-    "hose",
-    "hospital",
-    "hot dog",
-    "hot tub",
-    "hot",
-    "hotel",
-    "hour",
-    "hourglass",
-    "house",
-    "houseboat",
-    "hovercraft",
-    "howl",
-    "hug",
-    "hula hoop",
-    "human",
-    "humidifier",
-    "humidity",
-    "hummingbird",
-    "hungry",
-    "hunter",
-    "hurdle",
-    "hurricane",
-    "hurt",
-    "husband",
-    "hut",
-    "hydrogen",
-    "ice",
-    "icicle",
-    "idea",
-    "imagine",
-    "implode",
-    "important",
-    "inch",
-    "ink",
-    "inn",
-    "inquisition",
-    "insect",
-    "interception",
-    "intern",
-    "internet",
-    "invent",
-    "invitation",
-    "iron",
-    "ironic",
-    "ironing board",
-    "irrigation",
-    "island",
-    "ivory",
-    "ivy",
-    "jacket",
-    "jade",
-    "jail",
-    "janitor",
-    "japan",
-    "jar",
-    "jaw",
-    "jazz",
-    "jeans",
-    "jelly",
-    "jet ski",
-    "jet",
-    "jewelry",
-    "jig",
-    "jigsaw",
-    "jog",
-    "journal",
-    "judge",
-    "juggle",
-    "juice",
-    "jump",
-    "jungle",
-    "junk",
-    "kayak",
-    "kettle",
-    "key",
-    "killer",
-    "kilogram",
-    "king",
-    "kiss",
-    "kitchen",
-    "kite",
-    "knee",
-    "kneel",
-    "knife",
-    "knight",
-    "knot",
-    "koala",
-    "lace",
-    "ladder",
-    "ladybug",
-    "lag",
-    "lake",
-    "lamp",
-    "lance",
-    "landfill",
-    "landlord",
-    "landscape",
-    "lap",
-    "laser",
-    "last",
-    "latitude",
-    "laugh",
-    "laundry basket",
-    "laundry detergent",
-    "laundry",
-    "law",
-    "lawn",
-    "lawnmower",
-    "leak",
-    "learn",
-    "leather",
-    "lecture",
-    "leg",
-    "lemon",
-    "letter opener",
-    "letter",
-    "level",
-    "librarian",
-    "library",
-    "lifejacket",
-    "lifestyle",
-    "ligament",
-    "light switch",
-    "light",
-    "lighthouse",
-    "lightsaber",
-    "lime",
-    "limit",
-    "limousine",
-    "lion",
-    "lip",
-    "lipstick",
-    "liquid",
-    "list",
-    "living room",
-    "lizard",
-    "loaf",
-    "lobster",
-    "lock",
-    "locket",
-    "log",
-    "logo",
-    "lollipop",
-    "loveseat",
-    "loyalty",
-    "lucky",
-    "lullaby",
-    "lumberyard",
-    "lunar rover",
-    "lunch tray",
-    "lunch",
-    "lunchbox",
-    "lung",
-    "lyrics",
-    "macaroni",
-    "machine",
-    "macho",
-    "magazine",
-    "magic carpet",
-    "magic",
-    "magnet",
-    "maid",
-    "mail",
-    "mailbox",
-    "mailman",
-    "mammoth",
-    "manatee",
-    "map",
-    "mark",
-    "marker",
-    "marry",
-    "mars",
-    "marshmallow",
-    "mascot",
-    "mask",
-    "mast",
-    "mat",
-    "match",
-    "matchstick",
-    "mattress",
-    "mayor",
-    "maze",
-    "meat",
-    "melt",
-    "merry go round",
-    "mess",
-    "meteor",
-    "mexico",
-    "middle",
-    "midnight",
-    "midsummer",
-    "migrate",
-    "milk",
-    "mime",
-    "mine",
-    "miner",
-    "minivan",
-    "mirror",
-    "mistake",
-    "mitten",
-    "modern",
-    "molar",
-    "mold",
-    "molecule",
-    "mom",
-    "monday",
-    "money",
-    "monitor",
-    "monkey",
-    "monsoon",
-    "monster",
-    "mooch",
-    "moon",
-    "mop",
-    "moth",
-    "motorcycle",
-    "mountain",
-    "mouse pad",
-    "mouse",
-    "mouth",
-    "movie theater",
-    "movie",
-    "mower",
-    "mud",
-    "muffin",
-    "mug",
-    "muscle",
-    "museum",
-    "mushroom",
-    "music",
-    "musician",
-    "mute",
-    "mysterious",
-    "myth",
-    "nail",
-    "nanny",
-    "nap",
-    "napkin",
-    "narwhal",
-    "nature",
-    "neck",
-    "necktie",
-    "needle",
-    "negotiate",
-    "neighbor",
-    "neighborhood",
-    "nest",
-    "net",
-    "neutron",
-    "newborn",
-    "newlywed",
-    "newsletter",
-    "newspaper",
-    "niece",
-    "night",
-    "nightmare",
-    "noon",
-    "north",
-    "nose",
-    "notebook",
-    "notepad",
-    "nun",
-    "nurse",
-    "nut",
-    "oar",
-    "obey",
-    "observatory",
-    "ocean",
-    "office",
-    "oil",
-    "old",
-    "olympian",
-    "omnivore",
-    "onion",
-    "opaque",
-    "open",
-    "opener",
-    "optometrist",
-    "orange",
-    "orbit",
-    "organ",
-    "organize",
-    "ornament",
-    "orphan",
-    "ounce",
-    "outer space",
-    "outer",
-    "outside",
-    "ovation",
-    "oven",
-    "overture",
-    "owl",
-    "owner",
-    "oxcart",
-    "package",
-    "page",
-    "pail",
-    "pain",
-    "paint",
-    "pajamas",
-    "palace",
-    "pan",
-    "pancake",
-    "panda",
-    "pantry",
-    "pants",
-    "paper",
-    "paperclip",
-    "parachute",
-    "parade",
-    "parent",
-    "park",
-    "parka",
-    "parking garage",
-    "parody",
-    "partner",
-    "party",
-    "passenger",
-    "password",
-    "pastry",
-    "paw",
-    "pawn",
-    "pea",
-    "peace",
-    "peach",
-    "peanut",
-    "pear",
-    "peasant",
-    "peck",
-    "pelican",
-    "pen",
-    "pencil",
-    "pendulum",
-    "penguin",
-    "penny",
-    "pepper",
-    "personal",
-    "pest",
-    "pet store",
-    "pet",
-    "pharaoh",
-    "pharmacist",
-    "philosopher",
-    "phone",
-    "photograph",
-    "photosynthesis",
-    "piano",
-    "pickle",
-    "pickup truck",
-    "picnic",
-    "picture frame",
-    "pie",
-    "pigpen",
-    "pile",
-    "pillow",
-    "pillowcase",
-    "pilot",
-    "pinch",
-    "pine tree",
-    "pineapple",
-    "pinecone",
-    "ping pong",
-    "ping",
-    "pinwheel",
-    "pipe",
-    "piranha",
-    "pirate",
-    "pitchfork",
-    "pizza sauce",
-    "pizza",
-    "plaid",
-    "plan",
-    "plank",
-    "plant",
-    "plantation",
-    "plastic",
-    "plate",
-    "platypus",
-    "playground",
-    "plow",
-    "plug",
-    "plumber",
-    "pocket",
-    "poem",
-    "pogo stick",
-    "point",
-    "poison",
-    "pole",
-    "police",
-    "pollution",
-    "pomp",
-    "pond",
-    "pong",
-    "poodle",
-    "pool",
-    "pop",
-    "popcorn",
-    "popsicle",
-    "population",
-    "porch",
-    "porcupine",
-    "portfolio",
-    "porthole",
-    "positive",
-    "post office",
-    "post",
-    "postcard",
-    "pot",
-    "potato",
-    "powder",
-    "present",
-    "president",
-    "pretzel",
-    "prey",
-    "prime meridian",
-    "prince",
-    "princess",
-    "print",
-    "printer ink",
-    "printer",
-    "prize",
-    "pro",
-    "procrastinate",
-    "produce",
-    "professor",
-    "propeller",
-    "propose",
-    "protestant",
-    "psychologist",
-    "publisher",
-    "puddle",
-    "pulley",
-    "pumpkin",
-    "punk",
-    "puppet",
-    "puppy",
-    "purse",
-    "push",
-    "putty",
-    "puzzle",
-    "quadrant",
-    "quadruplets",
-    "quarantine",
-    "quarter",
-    "quartz",
-    "queen",
-    "quicksand",
-    "quiet",
-    "quilt",
-    "quit",
-    "race car",
-    "race",
-    "radio",
-    "radish",
-    "raft",
-    "rag",
-    "railroad",
-    "rain",
-    "rainbow",
-    "rainstorm",
-    "rainwater",
-    "rake",
-    "random",
-    "rat",
-    "ratchet",
-    "rattle",
-    "ray",
-    "razor",
-    "read",
-    "ream",
-    "receipt",
-    "recess",
-    "record",
-    "recycle",
-    "red",
-    "refrigerator",
-    "regret",
-    "reindeer",
-    "religion",
-    "reservoir",
-    "restaurant",
-    "retail",
-    "retaliate",
-    "reveal",
-    "rhinoceros",
-    "rhythm",
-    "rib",
-    "ribbon",
-    "rice",
-    "riddle",
-    "right",
-    "rim",
-    "rind",
-    "ring",
-    "ringleader",
-    "rink",
-    "river",
-    "road",
-    "robe",
-    "robin",
-    "rock",
-    "rocket",
-    "rocking chair",
-    "rodeo",
-    "roller blades",
-    "roller coaster",
-    "roller",
-    "roof",
-    "room",
-    "roommate",
-    "root",
-    "rope",
-    "rose",
-    "round",
-    "roundabout",
-    "rowboat",
-    "rubber",
-    "ruby",
-    "rudder",
-    "rug",
-    "run",
-    "rung",
-    "runoff",
-    "runt",
-    "rut",
-    "sack",
-    "sad",
-    "saddle",
-    "safe",
-    "safety goggles",
-    "sail",
-    "sailboat",
-    "salmon",
-    "salt and pepper",
-    "salt",
-    "saltwater",
-    "sand",
-    "sandal",
-    "sandbox",
-    "sandcastle",
-    "sandpaper",
-    "sandwich",
-    "sap",
-    "sash",
-    "satellite",
-    "save",
-    "saw",
-    "saxophone",
-    "scale",
-    "scar",
-    "scarecrow",
-    "scared",
-    "scarf",
-    "school bus",
-    "school",
-    "science",
-    "scientist",
-    "scissors",
-    "scoundrel",
-    "scramble",
-    "scream",
-    "screwdriver",
-    "script",
-    "scuba diving",
-    "scuff",
-    "sea turtle",
-    "seahorse",
-    "seal",
-    "seashell",
-    "season",
-    "seat",
-    "seaweed",
-    "seed",
-    "seesaw",
-    "sentence",
-    "sequins",
-    "servant",
-    "set",
-    "shack",
-    "shade",
-    "shadow",
-    "shaft",
-    "shake",
-    "shallow",
-    "shampoo",
-    "shape",
-    "shark",
-    "sheep dog",
-    "sheep",
-    "sheets",
-    "shelf",
-    "shelter",
-    "sheriff",
-    "ship",
-    "shipwreck",
-    "shirt",
-    "shoelace",
-    "shopping cart",
-    "short",
-    "shoulder",
-    "shovel",
-    "shower curtain",
-    "shower",
-    "shrew",
-    "shrink ray",
-    "shrink",
-    "sick",
-    "sidekick",
-    "sidewalk",
-    "siesta",
-    "sign",
-    "signal",
-    "silhouette",
-    "silverware",
-    "singer",
-    "sink",
-    "sip",
-    "sister",
-    "sit",
-    "skate",
-    "skateboard",
-    "skating rink",
-    "skating",
-    "ski goggles",
-    "ski lift",
-    "ski",
-    "skirt",
-    "skunk",
-    "sky",
-    "slam",
-    "sled",
-    "sleep",
-    "sleeping bag",
-    "sleeve",
-    "slide",
-    "sling",
-    "slope",
-    "slow",
-    "slump",
-    "smile",
-    "smith",
-    "smoke",
-    "snag",
-    "snail",
-    "snarl",
-    "sneeze",
-    "snooze",
-    "snore",
-    "snow",
-    "snowball",
-    "snowboarding",
-    "snowflake",
-    "snuggle",
-    "soak",
-    "soap",
-    "soccer",
-    "sock",
-    "soda",
-    "softball",
-    "solar system",
-    "somersault",
-    "song",
-    "soup",
-    "space",
-    "spaceship",
-    "spare",
-    "speakers",
-    "spear",
-    "spell",
-    "spider web",
-    "spider",
-    "spill",
-    "spine",
-    "spit",
-    "sponge",
-    "spool",
-    "spoon",
-    "spot",
-    "spring",
-    "sprinkler",
-    "spy",
-    "square",
-    "squint",
-    "squirrel",
-    "stadium",
-    "stage fright",
-    "stage",
-    "stain",
-    "stairs",
-    "stamp",
-    "standing",
-    "staple",
-    "stapler",
-    "star",
-    "starfish",
-    "start",
-    "startup",
-    "state",
-    "stationery",
-    "stay",
-    "steam",
-    "stem",
-    "step",
-    "stew",
-    "stick",
-    "sticky note",
-    "stingray",
-    "stockholder",
-    "stocking",
-    "stomach",
-    "stoplight",
-    "stopwatch",
-    "store",
-    "stork",
-    "storm",
-    "story",
-    "stout",
-    "stove",
-    "stow",
-    "stowaway",
-    "strap",
-    "straw",
-    "strawberry",
-    "stream",
-    "streamline",
-    "string",
-    "stripe",
-    "stroller",
-    "student",
-    "stuffed animal",
-    "stump",
-    "stutter",
-    "submarine",
-    "subway",
-    "sugar",
-    "suit",
-    "suitcase",
-    "summer",
-    "sun",
-    "sunburn",
-    "sunflower",
-    "sunglasses",
-    "sunrise",
-    "sunset",
-    "surfboard",
-    "surround",
-    "sushi",
-    "swamp",
-    "swarm",
-    "sweater vest",
-    "sweater",
-    "swimming pool",
-    "swimming",
-    "swing",
-    "swoop",
-    "sword",
-    "synchronized swimming",
-    "table",
-    "tablespoon",
-    "tachometer",
-    "tackle",
-    "tadpole",
-    "tag",
-    "tail",
-    "talk",
-    "tank",
-    "tape",
-    "target",
-    "taxes",
-    "taxi",
-    "taxidermist",
-    "teacher",
-    "team",
-    "teapot",
-    "tearful",
-    "teenager",
-    "teeth",
-    "telephone booth",
-    "telephone",
-    "television",
-    "ten",
-    "tennis",
-    "tent",
-    "testify",
-    "thaw",
-    "thermometer",
-    "thief",
-    "think",
-    "third plate",
-    "three toed sloth",
-    "thrift store",
-    "throat",
-    "throne",
-    "through",
-    "thumb",
-    "thunder",
-    "ticket",
-    "tide",
-    "tie",
-    "tiger",
-    "tightrope",
-    "time machine",
-    "time",
-    "timer",
-    "tin",
-    "tinting",
-    "tip",
-    "tiptoe",
-    "tiptop",
-    "tire",
-    "tired",
-    "tissue",
-    "toast",
-    "toaster",
-    "toddler",
-    "toe",
-    "toilet paper",
-    "toilet",
-    "toll road",
-    "tongs",
-    "tongue",
-    "tool",
-    "toolbox",
-    "tooth",
-    "toothbrush",
-    "toothpaste",
-    "top hat",
-    "torch",
-    "tornado",
-    "tourist",
-    "tournament",
-    "tow truck",
-    "tow",
-    "towel",
-    "tower",
-    "toy store",
-    "toy",
-    "tractor",
-    "traffic jam",
-    "trail",
-    "train",
-    "trampoline",
-    "trap",
-    "trapeze",
-    "trapped",
-    "trash can",
-    "trash",
-    "treasure",
-    "tree",
-    "triangle",
-    "tricycle",
-    "trip",
-    "trombone",
-    "trophy",
-    "truck stop",
-    "truck",
-    "trumpet",
-    "trunk",
-    "tub",
-    "tuba",
-    "tugboat",
-    "tulip",
-    "turkey",
-    "turtleneck",
-    "tusk",
-    "tutor",
-    "twang",
-    "twig",
-    "twist",
-    "type",
-    "umbrella",
-    "unemployed",
-    "unicorn",
-    "unicycle",
-    "unite",
-    "university",
-    "upgrade",
-    "vacation",
-    "van",
-    "vanilla",
-    "vanish",
-    "vase",
-    "vegetable",
-    "vegetarian",
-    "vehicle",
-    "vest",
-    "vet",
-    "video camera",
-    "violin",
-    "vision",
-    "vitamin",
-    "volcano",
-    "volleyball",
-    "waffle",
-    "wag",
-    "wagon",
-    "waist",
-    "wall",
-    "wallet",
-    "wallow",
-    "washing machine",
-    "watch",
-    "water buffalo",
-    "water cycle",
-    "water",
-    "waterfall",
-    "watering can",
-    "watermelon",
-    "wave",
-    "wax",
-    "weather",
-    "wedding cake",
-    "wedding",
-    "wedge",
-    "weight",
-    "welder",
-    "well",
-    "whatever",
-    "wheelbarrow",
-    "wheelchair",
-    "wheelie",
-    "whiplash",
-    "whisk",
-    "whistle",
-    "white",
-    "wick",
-    "wig",
-    "win",
-    "wind",
-    "windmill",
-    "window",
-    "windshield",
-    "wing",
-    "winter",
-    "wish",
-    "wobble",
-    "wolf",
-    "wood",
-    "wool",
-    "world",
-    "worm",
-    "wrap",
-    "wreath",
-    "wreck",
-    "wrench",
-    "wrist",
-    "wristwatch",
-    "yacht",
-    "yak",
-    "yard",
-    "yardstick",
-    "yarn",
-    "yawn",
-    "yo yo",
-    "yodel",
-    "yolk",
-    "zamboni",
-    "zebra",
-    "zen",
-    "zero",
-    "zipper",
-    "zone",
-    "zoo",
-    "zookeeper",
-    "zoom",
-];
@@ -0,0 +1,42 @@
+pub const MUSIC: &[&str] = &[
+    "applause",
+    "bagpipe",
+    "banjo",
+    "beethoven",
+    "bell",
+    "cello",
+    "chime",
+    "disc jockey",
+    "drums",
+    "fiddle",
+    "flute",
+    "guitar",
+    "harmonica",
+    "harp",
+    "horn",
+    "jazz",
+    "lullaby",
+    "lyrics",
+    "music",
+    "musician",
+    "organ",
+    "ovation",
+    "overture",
+    "piano",
+    "poem",
+    "rattle",
+    "record",
+    "rhythm",
+    "saxophone",
+    "singer",
+    "song",
+    "stage fright",
+    "stage",
+    "trombone",
+    "trumpet",
+    "tuba",
+    "twang",
+    "violin",
+    "whistle",
+    "yodel",
+];
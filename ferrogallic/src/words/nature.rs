@@ -0,0 +1,135 @@
+pub const NATURE: &[&str] = &[
+    "beach",
+    "black hole",
+    "blizzard",
+    "blossom",
+    "bushes",
+    "cactus",
+    "clay",
+    "cliff",
+    "coal",
+    "constellation",
+    "crater",
+    "darkness",
+    "dawn",
+    "day",
+    "density",
+    "dew",
+    "dirt",
+    "downpour",
+    "drought",
+    "dust",
+    "earthquake",
+    "ebony",
+    "eclipse",
+    "elm",
+    "evolution",
+    "fern",
+    "fire",
+    "flood",
+    "flower",
+    "fog",
+    "forest",
+    "fresh water",
+    "frost",
+    "full moon",
+    "fungus",
+    "gem",
+    "geyser",
+    "gold",
+    "grass",
+    "grasslands",
+    "gravity",
+    "hail",
+    "hill",
+    "humidity",
+    "hurricane",
+    "ice",
+    "icicle",
+    "irrigation",
+    "ivory",
+    "ivy",
+    "jade",
+    "lake",
+    "landscape",
+    "lawn",
+    "liquid",
+    "mars",
+    "meteor",
+    "midnight",
+    "midsummer",
+    "monsoon",
+    "moon",
+    "mountain",
+    "mud",
+    "nature",
+    "night",
+    "noon",
+    "ocean",
+    "oil",
+    "orbit",
+    "outer space",
+    "photosynthesis",
+    "pine tree",
+    "pinecone",
+    "plant",
+    "pond",
+    "puddle",
+    "quartz",
+    "quicksand",
+    "rain",
+    "rainbow",
+    "rainstorm",
+    "rainwater",
+    "reservoir",
+    "river",
+    "rock",
+    "root",
+    "rose",
+    "ruby",
+    "runoff",
+    "saltwater",
+    "sand",
+    "seashell",
+    "season",
+    "seaweed",
+    "seed",
+    "shade",
+    "shadow",
+    "sky",
+    "slope",
+    "smoke",
+    "snow",
+    "snowflake",
+    "solar system",
+    "space",
+    "spring",
+    "star",
+    "steam",
+    "stem",
+    "storm",
+    "stream",
+    "stump",
+    "summer",
+    "sun",
+    "sunflower",
+    "sunrise",
+    "sunset",
+    "swamp",
+    "thaw",
+    "thunder",
+    "tide",
+    "tornado",
+    "tree",
+    "tulip",
+    "twig",
+    "volcano",
+    "water cycle",
+    "water",
+    "waterfall",
+    "wave",
+    "weather",
+    "wind",
+    "winter",
+    "wood",
+];
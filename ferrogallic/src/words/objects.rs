@@ -0,0 +1,406 @@
+pub const OBJECTS: &[&str] = &[
+    "acre",
+    "address",
+    "anvil",
+    "armada",
+    "art",
+    "atlas",
+    "bag",
+    "baggage",
+    "balloon",
+    "banister",
+    "barrel",
+    "base",
+    "baseboards",
+    "basket",
+    "bathroom scale",
+    "bathtub",
+    "beanstalk",
+    "billboard",
+    "birthday",
+    "blanket",
+    "bleach",
+    "bob",
+    "bomb",
+    "book",
+    "bookend",
+    "bottle",
+    "box",
+    "brick",
+    "bridge",
+    "broom",
+    "brush",
+    "bubble",
+    "bucket",
+    "bug spray",
+    "cage",
+    "calendar",
+    "can",
+    "candle",
+    "cannon",
+    "carat",
+    "cardboard",
+    "carpet",
+    "cartoon",
+    "cash",
+    "cast",
+    "catalog",
+    "ceiling fan",
+    "ceiling",
+    "cell",
+    "century",
+    "chain",
+    "chair",
+    "chalk",
+    "chart",
+    "chimney",
+    "chip",
+    "chisel",
+    "clamp",
+    "class",
+    "clue",
+    "coaster",
+    "coin",
+    "commercial",
+    "compass",
+    "cone",
+    "cork",
+    "corner",
+    "cot",
+    "cover",
+    "crack",
+    "crane",
+    "crate",
+    "crayon",
+    "crib",
+    "cuckoo clock",
+    "curb",
+    "curtain",
+    "curtains",
+    "curve",
+    "cushion",
+    "dent",
+    "desk",
+    "diagonal",
+    "dictionary",
+    "ditch",
+    "dog leash",
+    "doll",
+    "dollar",
+    "door",
+    "doorknob",
+    "doormat",
+    "dot",
+    "drain",
+    "drawer",
+    "drill bit",
+    "drill",
+    "dump",
+    "dust bunny",
+    "dustpan",
+    "easel",
+    "edge",
+    "envelope",
+    "eraser",
+    "fan",
+    "faucet",
+    "fence",
+    "fire hydrant",
+    "fireman pole",
+    "fireside",
+    "flagpole",
+    "flashlight",
+    "floor",
+    "flotsam",
+    "foam",
+    "foil",
+    "fork",
+    "frame",
+    "front",
+    "frying pan",
+    "gallon",
+    "garbage",
+    "garden hose",
+    "gate",
+    "gift",
+    "glass",
+    "glitter",
+    "globe",
+    "glue gun",
+    "glue stick",
+    "glue",
+    "graph",
+    "gray",
+    "green",
+    "grill",
+    "hammer",
+    "hand soap",
+    "handle",
+    "handwriting",
+    "hatch",
+    "hedge",
+    "hole",
+    "hook",
+    "hoop",
+    "hose",
+    "hot tub",
+    "hour",
+    "hourglass",
+    "inch",
+    "ink",
+    "invitation",
+    "iron",
+    "ironing board",
+    "jar",
+    "jig",
+    "journal",
+    "junk",
+    "kettle",
+    "key",
+    "kilogram",
+    "knife",
+    "knot",
+    "ladder",
+    "lamp",
+    "lance",
+    "lap",
+    "last",
+    "laundry basket",
+    "laundry detergent",
+    "laundry",
+    "lawnmower",
+    "letter opener",
+    "letter",
+    "level",
+    "light",
+    "list",
+    "lock",
+    "log",
+    "loveseat",
+    "magazine",
+    "magic",
+    "mail",
+    "mailbox",
+    "map",
+    "mark",
+    "marker",
+    "mat",
+    "match",
+    "matchstick",
+    "mattress",
+    "maze",
+    "middle",
+    "mirror",
+    "mold",
+    "monday",
+    "money",
+    "mop",
+    "movie",
+    "mower",
+    "mug",
+    "nail",
+    "napkin",
+    "needle",
+    "net",
+    "newsletter",
+    "newspaper",
+    "notebook",
+    "notepad",
+    "opener",
+    "ornament",
+    "ounce",
+    "outer",
+    "outside",
+    "oven",
+    "package",
+    "page",
+    "pail",
+    "paint",
+    "pan",
+    "paper",
+    "paperclip",
+    "parade",
+    "parody",
+    "party",
+    "pen",
+    "pencil",
+    "pendulum",
+    "penny",
+    "pest",
+    "photograph",
+    "picture frame",
+    "pile",
+    "pillow",
+    "pillowcase",
+    "ping",
+    "pinwheel",
+    "pipe",
+    "pitchfork",
+    "plank",
+    "plantation",
+    "plastic",
+    "plate",
+    "plow",
+    "poison",
+    "pole",
+    "pollution",
+    "pomp",
+    "pool",
+    "pop",
+    "portfolio",
+    "post",
+    "postcard",
+    "pot",
+    "powder",
+    "present",
+    "print",
+    "protestant",
+    "punk",
+    "puppet",
+    "putty",
+    "quadrant",
+    "quadruplets",
+    "quarter",
+    "quilt",
+    "rag",
+    "rake",
+    "ratchet",
+    "ray",
+    "razor",
+    "ream",
+    "receipt",
+    "red",
+    "ribbon",
+    "right",
+    "rim",
+    "rocking chair",
+    "roller",
+    "roof",
+    "rope",
+    "round",
+    "rubber",
+    "rug",
+    "rung",
+    "rut",
+    "sack",
+    "saddle",
+    "safe",
+    "sandpaper",
+    "saw",
+    "scale",
+    "scissors",
+    "screwdriver",
+    "script",
+    "scuff",
+    "seat",
+    "sentence",
+    "set",
+    "shaft",
+    "shampoo",
+    "shape",
+    "sheets",
+    "shelf",
+    "shelter",
+    "shopping cart",
+    "shovel",
+    "shower curtain",
+    "shower",
+    "siesta",
+    "sign",
+    "silhouette",
+    "silverware",
+    "sink",
+    "sling",
+    "snag",
+    "snarl",
+    "snowball",
+    "soap",
+    "spare",
+    "spear",
+    "sponge",
+    "spool",
+    "spoon",
+    "spot",
+    "sprinkler",
+    "square",
+    "stain",
+    "stairs",
+    "stamp",
+    "staple",
+    "stapler",
+    "stationery",
+    "step",
+    "stick",
+    "sticky note",
+    "story",
+    "stout",
+    "stove",
+    "strap",
+    "straw",
+    "streamline",
+    "string",
+    "stripe",
+    "stuffed animal",
+    "suitcase",
+    "sword",
+    "table",
+    "tablespoon",
+    "tank",
+    "tape",
+    "target",
+    "teapot",
+    "ten",
+    "third plate",
+    "throne",
+    "ticket",
+    "time",
+    "tin",
+    "tinting",
+    "tip",
+    "tiptoe",
+    "tiptop",
+    "tissue",
+    "toilet paper",
+    "toilet",
+    "tongs",
+    "tool",
+    "toolbox",
+    "toothbrush",
+    "toothpaste",
+    "torch",
+    "tow",
+    "towel",
+    "tower",
+    "toy",
+    "trail",
+    "trap",
+    "trash can",
+    "trash",
+    "treasure",
+    "triangle",
+    "trunk",
+    "tub",
+    "umbrella",
+    "vase",
+    "vitamin",
+    "wall",
+    "watering can",
+    "wax",
+    "wedding",
+    "wedge",
+    "weight",
+    "well",
+    "whiplash",
+    "whisk",
+    "white",
+    "wick",
+    "windmill",
+    "window",
+    "wrap",
+    "wreath",
+    "wreck",
+    "wrench",
+    "yardstick",
+    "zero",
+    "zone",
+];
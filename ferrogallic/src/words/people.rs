@@ -0,0 +1,203 @@
+pub const PEOPLE: &[&str] = &[
+    "accounting",
+    "acne",
+    "acrobat",
+    "actor",
+    "angel",
+    "ankle",
+    "archaeologist",
+    "aristocrat",
+    "arm",
+    "artist",
+    "astronaut",
+    "athlete",
+    "aunt",
+    "baby",
+    "babysitter",
+    "back",
+    "backbone",
+    "bacteria",
+    "baker",
+    "bald",
+    "barber",
+    "best friend",
+    "blacksmith",
+    "blush",
+    "body",
+    "boy",
+    "brain",
+    "bride",
+    "bruise",
+    "brunette",
+    "buddy",
+    "butcher",
+    "captain",
+    "carpenter",
+    "chairman",
+    "champion",
+    "character",
+    "cheek",
+    "cheerleader",
+    "chef",
+    "cherub",
+    "chest",
+    "children",
+    "chin",
+    "clown",
+    "coach",
+    "cough",
+    "cousin",
+    "cowboy",
+    "coworker",
+    "customer",
+    "cuticle",
+    "czar",
+    "dad",
+    "dentist",
+    "detective",
+    "dimple",
+    "diver",
+    "dizzy",
+    "doctor",
+    "dragon",
+    "ear",
+    "earache",
+    "elbow",
+    "elf",
+    "expert",
+    "eyebrow",
+    "face",
+    "fairies",
+    "family",
+    "farmer",
+    "fiance",
+    "finger",
+    "firefighter",
+    "fist",
+    "florist",
+    "flu",
+    "forehead",
+    "freckle",
+    "freshman",
+    "frown",
+    "geologist",
+    "germ",
+    "giant",
+    "girl",
+    "girlfriend",
+    "goalkeeper",
+    "goblin",
+    "grandma",
+    "grandpa",
+    "groom",
+    "hair",
+    "headache",
+    "heart",
+    "heel",
+    "hero",
+    "hip",
+    "hipster",
+    "homeless",
+    "human",
+    "hunter",
+    "hurt",
+    "husband",
+    "intern",
+    "janitor",
+    "jaw",
+    "judge",
+    "killer",
+    "king",
+    "knee",
+    "knight",
+    "landlord",
+    "leg",
+    "librarian",
+    "ligament",
+    "lip",
+    "lung",
+    "maid",
+    "mailman",
+    "mascot",
+    "mayor",
+    "mime",
+    "miner",
+    "molar",
+    "mom",
+    "monster",
+    "mouth",
+    "muscle",
+    "nanny",
+    "neck",
+    "neighbor",
+    "newborn",
+    "newlywed",
+    "niece",
+    "nose",
+    "nun",
+    "nurse",
+    "olympian",
+    "optometrist",
+    "orphan",
+    "owner",
+    "parent",
+    "partner",
+    "passenger",
+    "peasant",
+    "pharaoh",
+    "pharmacist",
+    "philosopher",
+    "pilot",
+    "pirate",
+    "plumber",
+    "police",
+    "president",
+    "prince",
+    "princess",
+    "professor",
+    "psychologist",
+    "publisher",
+    "queen",
+    "rib",
+    "ringleader",
+    "roommate",
+    "runt",
+    "scar",
+    "scarecrow",
+    "scoundrel",
+    "servant",
+    "sheriff",
+    "shoulder",
+    "sick",
+    "sidekick",
+    "sister",
+    "smile",
+    "smith",
+    "sneeze",
+    "spine",
+    "spy",
+    "stockholder",
+    "stomach",
+    "stowaway",
+    "student",
+    "sunburn",
+    "taxidermist",
+    "teacher",
+    "teenager",
+    "teeth",
+    "thief",
+    "throat",
+    "thumb",
+    "toddler",
+    "toe",
+    "tongue",
+    "tooth",
+    "tourist",
+    "tutor",
+    "unicorn",
+    "vet",
+    "waist",
+    "welder",
+    "wrist",
+    "zookeeper",
+];
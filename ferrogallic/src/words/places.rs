@@ -0,0 +1,115 @@
+pub const PLACES: &[&str] = &[
+    "airport security",
+    "airport",
+    "aisle",
+    "america",
+    "amusement park",
+    "aquarium",
+    "arcade",
+    "art gallery",
+    "atlantis",
+    "attic",
+    "barbershop",
+    "barn",
+    "bookstore",
+    "booth",
+    "border",
+    "boulevard",
+    "cabin",
+    "campsite",
+    "canada",
+    "car dealership",
+    "carnival",
+    "castle",
+    "cave",
+    "cellar",
+    "cemetery",
+    "china",
+    "church",
+    "circus",
+    "city",
+    "classroom",
+    "coast",
+    "coastline",
+    "college",
+    "company",
+    "concession stand",
+    "country",
+    "cubicle",
+    "dead end",
+    "desert",
+    "dock",
+    "driveway",
+    "drugstore",
+    "east",
+    "end zone",
+    "england",
+    "equator",
+    "europe",
+    "factory",
+    "farm",
+    "field",
+    "fortress",
+    "france",
+    "front porch",
+    "garage",
+    "garden",
+    "gas station",
+    "grocery store",
+    "highway",
+    "hospital",
+    "hotel",
+    "house",
+    "houseboat",
+    "hut",
+    "inn",
+    "island",
+    "jail",
+    "japan",
+    "jungle",
+    "kitchen",
+    "landfill",
+    "latitude",
+    "library",
+    "lighthouse",
+    "living room",
+    "lumberyard",
+    "mexico",
+    "mine",
+    "movie theater",
+    "museum",
+    "neighborhood",
+    "north",
+    "office",
+    "palace",
+    "pantry",
+    "park",
+    "parking garage",
+    "pet store",
+    "playground",
+    "porch",
+    "post office",
+    "prime meridian",
+    "railroad",
+    "rink",
+    "road",
+    "room",
+    "roundabout",
+    "school",
+    "shack",
+    "sidewalk",
+    "skating rink",
+    "stadium",
+    "state",
+    "store",
+    "subway",
+    "swimming pool",
+    "thrift store",
+    "toll road",
+    "toy store",
+    "truck stop",
+    "university",
+    "world",
+    "yard",
+    "zoo",
+];
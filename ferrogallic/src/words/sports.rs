@@ -0,0 +1,85 @@
+pub const SPORTS: &[&str] = &[
+    "back flip",
+    "balance beam",
+    "baseball",
+    "basketball",
+    "bobsled",
+    "boxing",
+    "campfire",
+    "carousel",
+    "chariot racing",
+    "chess",
+    "cliff diving",
+    "competition",
+    "dart",
+    "darts",
+    "dodgeball",
+    "dominoes",
+    "dunk",
+    "exercise",
+    "fishing pole",
+    "fishing",
+    "football",
+    "game",
+    "gold medal",
+    "golf",
+    "hang glider",
+    "hockey",
+    "hopscotch",
+    "hula hoop",
+    "hurdle",
+    "interception",
+    "jet ski",
+    "jigsaw",
+    "jog",
+    "juggle",
+    "kayak",
+    "kite",
+    "merry go round",
+    "parachute",
+    "pawn",
+    "ping pong",
+    "pogo stick",
+    "pong",
+    "prize",
+    "puzzle",
+    "race",
+    "riddle",
+    "rodeo",
+    "roller blades",
+    "roller coaster",
+    "sandbox",
+    "sandcastle",
+    "scuba diving",
+    "seesaw",
+    "skate",
+    "skateboard",
+    "skating",
+    "ski lift",
+    "ski",
+    "sled",
+    "sleeping bag",
+    "slide",
+    "snowboarding",
+    "soccer",
+    "softball",
+    "somersault",
+    "surfboard",
+    "swimming",
+    "swing",
+    "synchronized swimming",
+    "tackle",
+    "tag",
+    "team",
+    "tennis",
+    "tent",
+    "tightrope",
+    "tournament",
+    "trampoline",
+    "trapeze",
+    "trophy",
+    "volleyball",
+    "wheelie",
+    "win",
+    "yo yo",
+];
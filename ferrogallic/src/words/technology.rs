@@ -0,0 +1,81 @@
+pub const TECHNOLOGY: &[&str] = &[
+    "alarm clock",
+    "application",
+    "batteries",
+    "battery",
+    "blueprint",
+    "bulb",
+    "calculator",
+    "camera",
+    "cell phone charger",
+    "cell phone",
+    "charger",
+    "chemical",
+    "clockwork",
+    "cog",
+    "coil",
+    "computer",
+    "conveyor belt",
+    "cord",
+    "download",
+    "dryer",
+    "electrical outlet",
+    "electricity",
+    "elevator",
+    "engine",
+    "ergonomic",
+    "escalator",
+    "eureka",
+    "extension cord",
+    "extension",
+    "fax",
+    "hair dryer",
+    "heater",
+    "helium",
+    "humidifier",
+    "hydrogen",
+    "internet",
+    "invent",
+    "laser",
+    "light switch",
+    "lightsaber",
+    "logo",
+    "lunar rover",
+    "machine",
+    "magnet",
+    "molecule",
+    "monitor",
+    "mouse pad",
+    "neutron",
+    "observatory",
+    "password",
+    "phone",
+    "plug",
+    "printer ink",
+    "printer",
+    "propeller",
+    "pulley",
+    "radio",
+    "refrigerator",
+    "rocket",
+    "satellite",
+    "science",
+    "scientist",
+    "shrink ray",
+    "signal",
+    "spaceship",
+    "speakers",
+    "startup",
+    "stopwatch",
+    "tachometer",
+    "telephone booth",
+    "telephone",
+    "television",
+    "thermometer",
+    "time machine",
+    "timer",
+    "toaster",
+    "upgrade",
+    "video camera",
+    "washing machine",
+];
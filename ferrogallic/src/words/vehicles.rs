@@ -0,0 +1,72 @@
+pub const VEHICLES: &[&str] = &[
+    "aircraft carrier",
+    "aircraft",
+    "ambulance",
+    "bicycle",
+    "bike",
+    "blimp",
+    "boat",
+    "buggy",
+    "bus",
+    "cable car",
+    "canoe",
+    "car",
+    "cargo",
+    "chariot",
+    "cockpit",
+    "crop duster",
+    "crow's nest",
+    "cruise ship",
+    "cruise",
+    "dashboard",
+    "dump truck",
+    "eighteen wheeler",
+    "ferry",
+    "first class",
+    "freight",
+    "garbage truck",
+    "gasoline",
+    "golf cart",
+    "helicopter",
+    "hovercraft",
+    "jet",
+    "limousine",
+    "magic carpet",
+    "mast",
+    "minivan",
+    "motorcycle",
+    "oar",
+    "oxcart",
+    "pickup truck",
+    "porthole",
+    "race car",
+    "raft",
+    "rowboat",
+    "rudder",
+    "sail",
+    "sailboat",
+    "school bus",
+    "ship",
+    "shipwreck",
+    "stoplight",
+    "stroller",
+    "submarine",
+    "taxi",
+    "tire",
+    "tow truck",
+    "tractor",
+    "traffic jam",
+    "train",
+    "tricycle",
+    "truck",
+    "tugboat",
+    "unicycle",
+    "van",
+    "vehicle",
+    "wagon",
+    "wheelbarrow",
+    "wheelchair",
+    "windshield",
+    "yacht",
+    "zamboni",
+];
@@ -1,10 +1,24 @@
 use crate::api::WsEndpoint;
-use crate::config::{DEFAULT_GUESS_SECONDS, DEFAULT_ROUNDS};
-use crate::domain::{Color, Epoch, Guess, I12Pair, LineWidth, Lobby, Lowercase, Nickname, UserId};
+use crate::config::{
+    DEFAULT_CHOOSE_SECONDS, DEFAULT_CUSTOM_WORDS_PERCENT, DEFAULT_DRAWER_GRACE_SECONDS,
+    DEFAULT_DRAW_IDLE_SECONDS, DEFAULT_GUESS_SECONDS, DEFAULT_HINTS, DEFAULT_MAX_PLAYERS,
+    DEFAULT_REMOVE_DISCONNECTED_SECONDS, DEFAULT_ROUNDS, DEFAULT_SHORTENED_SECONDS,
+    DEFAULT_SHORTEN_AFTER_PERCENT, DEFAULT_SKIP_DISCONNECTED_SECONDS, MAX_CUSTOM_WORDS,
+    VALID_CHOOSE_SECONDS, VALID_CUSTOM_WORDS_PERCENT, VALID_CUSTOM_WORD_LENGTH,
+    VALID_DRAWER_GRACE_SECONDS, VALID_DRAW_IDLE_SECONDS, VALID_GUESS_SECONDS, VALID_HINTS,
+    VALID_MAX_PLAYERS, VALID_PASSWORD_LENGTH, VALID_REMOVE_DISCONNECTED_SECONDS, VALID_ROUNDS,
+    VALID_SHORTENED_SECONDS, VALID_SHORTEN_AFTER_PERCENT, VALID_SKIP_DISCONNECTED_SECONDS,
+};
+use crate::domain::{
+    Color, Epoch, Guess, I12Pair, LineWidth, Lobby, Lowercase, Nickname, SessionSecret, UserId,
+    WordPack,
+};
 use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+use std::ops::RangeInclusive;
 use std::sync::Arc;
-use time::OffsetDateTime;
+use time::{Duration, OffsetDateTime};
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub enum Game {
@@ -12,10 +26,12 @@ pub enum Game {
     Guess(Guess),
     Players(Arc<BTreeMap<UserId, Player>>),
     Game(Arc<GameState>),
-    Heartbeat,
+    Heartbeat(Box<ClockSync>),
     CanvasBulk(Vec<Canvas>),
     GuessBulk(Vec<Guess>),
     ClearGuesses,
+    Session(SessionSecret),
+    Closed(CloseReason),
 }
 
 #[test]
@@ -28,13 +44,103 @@ pub enum GameReq {
     Canvas(Canvas),
     Choose(Lowercase),
     Guess(Lowercase),
-    Join(Lobby, Nickname),
+    Join(Box<JoinReq>),
     Remove(UserId, Epoch<UserId>),
+    MakeHost(UserId),
+    Configure(Box<GameConfig>),
+    SetWords(Box<[Lowercase]>),
+    ResetWordHistory,
+    SetPassword(Option<Box<str>>),
+    VoteKick(UserId),
+    Heartbeat(OffsetDateTime),
+    SetPaused(bool),
 }
 
 #[test]
 fn gamereq_size() {
-    assert_eq!(std::mem::size_of::<GameReq>(), 40);
+    assert_eq!(std::mem::size_of::<GameReq>(), 24);
+}
+
+/// Timestamps for estimating how far a client's clock is from the server's.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ClockSync {
+    /// The server's clock when this was sent.
+    pub server: OffsetDateTime,
+    /// The client's clock when it sent the heartbeat this answers, if this is an answer.
+    pub echo: Option<OffsetDateTime>,
+}
+
+impl ClockSync {
+    /// Estimates how far ahead the server's clock is, assuming equal transit time each way.
+    pub fn offset(&self, received: OffsetDateTime) -> Option<Duration> {
+        let sent = self.echo?;
+        let round_trip = received - sent;
+        Some(self.server - (sent + round_trip / 2))
+    }
+}
+
+#[test]
+fn clock_sync_offset_splits_round_trip() {
+    let sent = OffsetDateTime::UNIX_EPOCH;
+    let sync = ClockSync {
+        server: sent + Duration::seconds(65),
+        echo: Some(sent),
+    };
+    assert_eq!(
+        sync.offset(sent + Duration::seconds(10)),
+        Some(Duration::minutes(1))
+    );
+    let heartbeat = ClockSync { echo: None, ..sync };
+    assert_eq!(heartbeat.offset(sent), None);
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct JoinReq {
+    pub lobby: Lobby,
+    pub nick: Nickname,
+    pub session: Option<SessionSecret>,
+    pub password: Option<Box<str>>,
+    pub role: Role,
+}
+
+#[derive(Debug, Deserialize, Serialize, Copy, Clone, PartialEq, Eq)]
+pub enum CloseReason {
+    NicknameInUse,
+    ServerShutdown,
+    PasswordRequired,
+    WrongPassword,
+    InvalidPassword,
+    TooManyAttempts,
+    Kicked,
+    Banned,
+}
+
+impl fmt::Display for CloseReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NicknameInUse => {
+                f.write_str("Someone else is already using that nickname in this lobby.")
+            }
+            Self::ServerShutdown => {
+                f.write_str("The server is restarting. Refresh the page in a minute to rejoin.")
+            }
+            Self::PasswordRequired => f.write_str("This lobby requires a password."),
+            Self::WrongPassword => f.write_str("The password for this lobby is incorrect."),
+            Self::InvalidPassword => write!(
+                f,
+                "Password must be between {} and {} characters.",
+                VALID_PASSWORD_LENGTH.start(),
+                VALID_PASSWORD_LENGTH.end()
+            ),
+            Self::TooManyAttempts => {
+                f.write_str("Too many wrong passwords for this lobby. Try again in a minute.")
+            }
+            Self::Kicked => f.write_str("You were voted out of this lobby."),
+            Self::Banned => f.write_str(
+                "You were recently voted out of this lobby. Try again in a few minutes.",
+            ),
+        }
+    }
 }
 
 impl WsEndpoint for Game {
@@ -46,12 +152,46 @@ impl WsEndpoint for Game {
 pub struct GameState {
     pub config: GameConfig,
     pub phase: GamePhase,
+    pub host: Option<UserId>,
+    pub custom_words: usize,
+    pub used_words: usize,
+    pub locked: bool,
+    /// Players waiting for a seat, in the order they'll get one.
+    pub queue: Vec<UserId>,
+    /// Set while the game is paused, during which the phase's timers are stopped.
+    pub paused: Option<Paused>,
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Copy, Clone, PartialEq)]
+pub struct Paused {
+    pub at: OffsetDateTime,
+    pub reason: PauseReason,
+}
+
+#[derive(Debug, Deserialize, Serialize, Copy, Clone, PartialEq, Eq)]
+pub enum PauseReason {
+    /// Only the host can resume the game.
+    Host,
+    /// Resumes if the drawer reconnects in time, otherwise the turn ends.
+    DrawerDisconnected,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 pub struct GameConfig {
     pub rounds: u8,
     pub guess_seconds: u16,
+    pub choose_seconds: u16,
+    pub hints: u8,
+    pub custom_words_percent: u8,
+    pub packs: BTreeSet<WordPack>,
+    pub public: bool,
+    pub max_players: u8,
+    pub skip_disconnected_seconds: u16,
+    pub remove_disconnected_seconds: u16,
+    pub draw_idle_seconds: u16,
+    pub shorten_after_percent: u8,
+    pub shortened_seconds: u16,
+    pub drawer_grace_seconds: u16,
 }
 
 impl Default for GameConfig {
@@ -59,10 +199,158 @@ impl Default for GameConfig {
         Self {
             rounds: DEFAULT_ROUNDS,
             guess_seconds: DEFAULT_GUESS_SECONDS,
+            choose_seconds: DEFAULT_CHOOSE_SECONDS,
+            hints: DEFAULT_HINTS,
+            custom_words_percent: DEFAULT_CUSTOM_WORDS_PERCENT,
+            packs: WordPack::ALL.iter().copied().collect(),
+            public: false,
+            max_players: DEFAULT_MAX_PLAYERS,
+            skip_disconnected_seconds: DEFAULT_SKIP_DISCONNECTED_SECONDS,
+            remove_disconnected_seconds: DEFAULT_REMOVE_DISCONNECTED_SECONDS,
+            draw_idle_seconds: DEFAULT_DRAW_IDLE_SECONDS,
+            shorten_after_percent: DEFAULT_SHORTEN_AFTER_PERCENT,
+            shortened_seconds: DEFAULT_SHORTENED_SECONDS,
+            drawer_grace_seconds: DEFAULT_DRAWER_GRACE_SECONDS,
+        }
+    }
+}
+
+impl GameConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        fn in_range<T: PartialOrd + fmt::Display>(
+            name: &str,
+            value: T,
+            range: RangeInclusive<T>,
+        ) -> Result<(), String> {
+            if range.contains(&value) {
+                Ok(())
+            } else {
+                Err(format!(
+                    "{} must be between {} and {}.",
+                    name,
+                    range.start(),
+                    range.end()
+                ))
+            }
+        }
+
+        in_range("Rounds", self.rounds, VALID_ROUNDS)?;
+        in_range("Guess seconds", self.guess_seconds, VALID_GUESS_SECONDS)?;
+        in_range("Choose seconds", self.choose_seconds, VALID_CHOOSE_SECONDS)?;
+        in_range("Hints", self.hints, VALID_HINTS)?;
+        in_range(
+            "Custom word percent",
+            self.custom_words_percent,
+            VALID_CUSTOM_WORDS_PERCENT,
+        )?;
+        in_range("Max players", self.max_players, VALID_MAX_PLAYERS)?;
+        in_range(
+            "Seconds before skipping",
+            self.skip_disconnected_seconds,
+            VALID_SKIP_DISCONNECTED_SECONDS,
+        )?;
+        in_range(
+            "Seconds before removing",
+            self.remove_disconnected_seconds,
+            VALID_REMOVE_DISCONNECTED_SECONDS,
+        )?;
+        in_range(
+            "Seconds before an idle drawer is skipped",
+            self.draw_idle_seconds,
+            VALID_DRAW_IDLE_SECONDS,
+        )?;
+        in_range(
+            "Percent of guessers before shortening",
+            self.shorten_after_percent,
+            VALID_SHORTEN_AFTER_PERCENT,
+        )?;
+        in_range(
+            "Shortened seconds",
+            self.shortened_seconds,
+            VALID_SHORTENED_SECONDS,
+        )?;
+        in_range(
+            "Seconds to wait for a disconnected drawer",
+            self.drawer_grace_seconds,
+            VALID_DRAWER_GRACE_SECONDS,
+        )?;
+        if self.remove_disconnected_seconds < self.skip_disconnected_seconds {
+            return Err("Disconnected players must be skipped before they're removed.".to_string());
+        }
+        if self.packs.is_empty() {
+            return Err("At least one word pack must be selected.".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Checks a list of custom words, dropping duplicates.
+pub fn validate_custom_words(
+    words: impl IntoIterator<Item = Lowercase>,
+) -> Result<Vec<Lowercase>, String> {
+    let mut seen = BTreeSet::new();
+    let mut valid = Vec::new();
+    for word in words {
+        if !VALID_CUSTOM_WORD_LENGTH.contains(&word.len()) {
+            return Err(format!(
+                "'{}' must be between {} and {} characters.",
+                word,
+                VALID_CUSTOM_WORD_LENGTH.start(),
+                VALID_CUSTOM_WORD_LENGTH.end()
+            ));
+        }
+        if !word
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || " '-".contains(c))
+        {
+            return Err(format!(
+                "'{}' may only contain letters, numbers, spaces, apostrophes, and hyphens.",
+                word
+            ));
+        }
+        if word.starts_with(' ') || word.ends_with(' ') || word.contains("  ") {
+            return Err(format!("'{}' has extra spaces.", word));
         }
+        if seen.insert(word.clone()) {
+            valid.push(word);
+        }
+    }
+    if valid.len() > MAX_CUSTOM_WORDS {
+        return Err(format!(
+            "At most {} custom words are allowed.",
+            MAX_CUSTOM_WORDS
+        ));
+    }
+    Ok(valid)
+}
+
+pub fn validate_password(password: &str) -> Result<(), String> {
+    if VALID_PASSWORD_LENGTH.contains(&password.chars().count()) {
+        Ok(())
+    } else {
+        Err(format!(
+            "Password must be between {} and {} characters.",
+            VALID_PASSWORD_LENGTH.start(),
+            VALID_PASSWORD_LENGTH.end()
+        ))
     }
 }
 
+#[test]
+fn custom_words_validation() {
+    let words = |ws: &[&str]| ws.iter().copied().map(Lowercase::new).collect::<Vec<_>>();
+    assert_eq!(
+        validate_custom_words(words(&["ferris", "Crow's Nest", "ferris", "x-ray"])),
+        Ok(words(&["ferris", "crow's nest", "x-ray"]))
+    );
+    assert!(validate_custom_words(words(&["a"])).is_err());
+    assert!(validate_custom_words(words(&["semi;colon"])).is_err());
+    assert!(validate_custom_words(words(&["double  space"])).is_err());
+    assert!(validate_custom_words(words(&["ok"; MAX_CUSTOM_WORDS + 1])).is_ok());
+    let too_many = (0..=MAX_CUSTOM_WORDS).map(|i| Lowercase::new(format!("word{}", i)));
+    assert!(validate_custom_words(too_many).is_err());
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub enum GamePhase {
     WaitingToStart,
@@ -70,12 +358,32 @@ pub enum GamePhase {
         round: u8,
         choosing: UserId,
         words: Arc<[Lowercase]>,
+        epoch: Epoch<GameState>,
+        started: OffsetDateTime,
     },
     Drawing {
         round: u8,
         drawing: UserId,
         correct: BTreeMap<UserId, u32>,
         word: Lowercase,
+        revealed: BTreeSet<usize>,
+        epoch: Epoch<GameState>,
+        started: OffsetDateTime,
+        /// When time runs out, which moves up once most players have guessed.
+        deadline: OffsetDateTime,
+        /// When the next letter is revealed, if there are any left.
+        next_hint: Option<OffsetDateTime>,
+        /// Roughly when the drawer last drew something, to within `DRAW_ACTIVITY_RESOLUTION_SECONDS`.
+        last_drawn: OffsetDateTime,
+    },
+    /// A short break after each turn, with the word revealed and the drawing still up.
+    RoundSummary {
+        round: u8,
+        drawing: UserId,
+        word: Lowercase,
+        /// Points earned by each player who guessed correctly.
+        correct: BTreeMap<UserId, u32>,
+        drawer_score: u32,
         epoch: Epoch<GameState>,
         started: OffsetDateTime,
     },
@@ -87,12 +395,120 @@ impl Default for GamePhase {
     }
 }
 
+impl GameState {
+    /// Returns a copy of this state as seen by players other than the privileged one.
+    pub fn redacted(&self) -> Self {
+        Self {
+            config: self.config.clone(),
+            phase: self.phase.redacted(),
+            host: self.host,
+            custom_words: self.custom_words,
+            used_words: self.used_words,
+            locked: self.locked,
+            queue: self.queue.clone(),
+            paused: self.paused,
+        }
+    }
+}
+
+impl GamePhase {
+    /// Stands in for each letter of a redacted word which hasn't been revealed.
+    pub const HIDDEN_CHAR: char = '_';
+
+    /// The player who is allowed to see the words being chosen or drawn, if any.
+    pub fn privileged(&self) -> Option<UserId> {
+        match self {
+            Self::WaitingToStart => None,
+            Self::ChoosingWords { choosing, .. } => Some(*choosing),
+            Self::Drawing { drawing, .. } => Some(*drawing),
+            Self::RoundSummary { .. } => None,
+        }
+    }
+
+    /// Returns a copy of this phase with the words hidden, except for the length, position of spaces, and revealed letters.
+    pub fn redacted(&self) -> Self {
+        match self {
+            Self::WaitingToStart => Self::WaitingToStart,
+            Self::ChoosingWords {
+                round,
+                choosing,
+                words: _,
+                epoch,
+                started,
+            } => Self::ChoosingWords {
+                round: *round,
+                choosing: *choosing,
+                words: Arc::new([]),
+                epoch: *epoch,
+                started: *started,
+            },
+            Self::Drawing {
+                round,
+                drawing,
+                correct,
+                word,
+                revealed,
+                epoch,
+                started,
+                deadline,
+                next_hint,
+                last_drawn,
+            } => Self::Drawing {
+                round: *round,
+                drawing: *drawing,
+                correct: correct.clone(),
+                word: Lowercase::new(
+                    word.chars()
+                        .enumerate()
+                        .map(|(i, c)| match c {
+                            ' ' => ' ',
+                            _ if revealed.contains(&i) => c,
+                            _ => Self::HIDDEN_CHAR,
+                        })
+                        .collect::<String>(),
+                ),
+                revealed: revealed.clone(),
+                epoch: *epoch,
+                started: *started,
+                deadline: *deadline,
+                next_hint: *next_hint,
+                last_drawn: *last_drawn,
+            },
+            // ...the word is no secret once the turn is over
+            Self::RoundSummary { .. } => self.clone(),
+        }
+    }
+}
+
+#[test]
+fn redacted_drawing_hides_word() {
+    let phase = GamePhase::Drawing {
+        round: 1,
+        drawing: Nickname::new("drawer").user_id(),
+        correct: Default::default(),
+        word: Lowercase::new("aircraft carrier"),
+        revealed: [1, 9].iter().copied().collect(),
+        epoch: Epoch::next(),
+        started: OffsetDateTime::UNIX_EPOCH,
+        deadline: OffsetDateTime::UNIX_EPOCH,
+        next_hint: None,
+        last_drawn: OffsetDateTime::UNIX_EPOCH,
+    };
+    match phase.redacted() {
+        GamePhase::Drawing { word, .. } => assert_eq!(word.as_str(), "_i______ c______"),
+        _ => unreachable!(),
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
 pub struct Player {
     pub nick: Nickname,
     pub epoch: Epoch<UserId>,
     pub status: PlayerStatus,
     pub score: u32,
+    pub role: Role,
+    /// Join order, so the host can be handed to whoever has been around longest.
+    pub joined: u32,
 }
 
 impl Player {
@@ -120,13 +536,24 @@ impl Player {
     }
 }
 
+/// Whether someone takes part in the game, or only watches it.
+#[derive(Debug, Deserialize, Serialize, Copy, Clone, PartialEq, Eq)]
+pub enum Role {
+    Player,
+    Spectator,
+    /// Wants to play, but the lobby was full.
+    Queued,
+}
+
 #[derive(Debug, Deserialize, Serialize, Copy, Clone, PartialEq)]
 pub enum PlayerStatus {
     Connected,
     Disconnected,
+    /// Disconnected for long enough that their turns are skipped.
+    Away,
 }
 
-#[derive(Debug, Deserialize, Serialize, Copy, Clone)]
+#[derive(Debug, Deserialize, Serialize, Copy, Clone, PartialEq)]
 pub enum Canvas {
     Line {
         from: I12Pair,
@@ -11,3 +11,48 @@ impl ApiEndpoint for RandomLobbyName {
     const PATH: &'static str = "random_lobby_name";
     type Req = ();
 }
+
+#[derive(Deserialize, Serialize)]
+pub struct PublicLobbies {
+    pub lobbies: Vec<LobbySummary>,
+}
+
+impl ApiEndpoint for PublicLobbies {
+    const PATH: &'static str = "public_lobbies";
+    type Req = ();
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct QuickPlay {
+    pub lobby: Lobby,
+}
+
+impl ApiEndpoint for QuickPlay {
+    const PATH: &'static str = "quick_play";
+    type Req = ();
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct LobbyInfo {
+    pub locked: bool,
+}
+
+impl ApiEndpoint for LobbyInfo {
+    const PATH: &'static str = "lobby_info";
+    type Req = Lobby;
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct LobbySummary {
+    pub lobby: Lobby,
+    pub players: usize,
+    pub max_players: usize,
+    pub phase: LobbyPhase,
+    pub locked: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize, Copy, Clone, PartialEq)]
+pub enum LobbyPhase {
+    WaitingToStart,
+    Playing { round: u8, rounds: u8 },
+}
@@ -1,3 +1,5 @@
+use std::ops::RangeInclusive;
+
 pub const MAX_REQUEST_BYTES: u64 = 4 * 1024;
 pub const MAX_WS_MESSAGE_BYTES: usize = 4 * 1024;
 
@@ -9,13 +11,56 @@ pub const CANVAS_WIDTH: usize = 800;
 pub const CANVAS_HEIGHT: usize = 600;
 
 pub const HEARTBEAT_SECONDS: u64 = 45;
+pub const QUICK_PLAY_MAX_PLAYERS: usize = 8;
+pub const QUICK_PLAY_RESERVATION_SECONDS: u64 = 60;
+pub const SHUTDOWN_FLUSH_SECONDS: u64 = 5;
+pub const VOTE_KICK_SECONDS: u64 = 60;
+pub const MIN_VOTE_KICK_VOTES: usize = 2;
+pub const KICK_BAN_SECONDS: u64 = 10 * 60;
+pub const MAX_PASSWORD_BACKOFF_SECONDS: u64 = 60;
+/// How long a lobby with nobody connected is kept around, which outlasts any removal timeout.
+pub const IDLE_LOBBY_SECONDS: u64 = 2 * 60 * 60;
 
 pub const NUMBER_OF_WORDS_TO_CHOOSE: usize = 3;
+pub const ROUND_SUMMARY_SECONDS: u16 = 5;
 pub const DEFAULT_ROUNDS: u8 = 3;
 pub const DEFAULT_GUESS_SECONDS: u16 = 120;
+pub const DEFAULT_CHOOSE_SECONDS: u16 = 20;
+pub const DEFAULT_HINTS: u8 = 2;
+pub const DEFAULT_CUSTOM_WORDS_PERCENT: u8 = 100;
+pub const DEFAULT_MAX_PLAYERS: u8 = 12;
+pub const DEFAULT_SKIP_DISCONNECTED_SECONDS: u16 = 30;
+pub const DEFAULT_REMOVE_DISCONNECTED_SECONDS: u16 = 5 * 60;
+pub const DEFAULT_DRAW_IDLE_SECONDS: u16 = 45;
+pub const DEFAULT_SHORTEN_AFTER_PERCENT: u8 = 75;
+pub const DEFAULT_SHORTENED_SECONDS: u16 = 20;
+pub const DEFAULT_DRAWER_GRACE_SECONDS: u16 = 30;
+pub const VALID_ROUNDS: RangeInclusive<u8> = 1..=10;
+pub const VALID_GUESS_SECONDS: RangeInclusive<u16> = 10..=600;
+pub const VALID_CHOOSE_SECONDS: RangeInclusive<u16> = 5..=120;
+pub const VALID_HINTS: RangeInclusive<u8> = 0..=5;
+pub const VALID_CUSTOM_WORDS_PERCENT: RangeInclusive<u8> = 0..=100;
+pub const VALID_MAX_PLAYERS: RangeInclusive<u8> = 2..=32;
+pub const VALID_SKIP_DISCONNECTED_SECONDS: RangeInclusive<u16> = 5..=600;
+pub const VALID_REMOVE_DISCONNECTED_SECONDS: RangeInclusive<u16> = 30..=3600;
+pub const VALID_DRAW_IDLE_SECONDS: RangeInclusive<u16> = 10..=600;
+pub const VALID_SHORTEN_AFTER_PERCENT: RangeInclusive<u8> = 1..=100;
+pub const VALID_SHORTENED_SECONDS: RangeInclusive<u16> = 5..=120;
+pub const VALID_DRAWER_GRACE_SECONDS: RangeInclusive<u16> = 5..=300;
+pub const VALID_CUSTOM_WORD_LENGTH: RangeInclusive<usize> = 2..=32;
+pub const MAX_CUSTOM_WORDS: usize = 100;
+pub const VALID_PASSWORD_LENGTH: RangeInclusive<usize> = 1..=64;
 pub const PERFECT_GUESS_SCORE: u32 = 500;
 pub const MINIMUM_GUESS_SCORE: u32 = 100;
 pub const FIRST_CORRECT_BONUS: u32 = 50;
+pub const HINT_PENALTY: u32 = 75;
+/// How stale the drawer's last stroke can get before another stroke is recorded.
+pub const DRAW_ACTIVITY_RESOLUTION_SECONDS: i64 = 5;
+
+pub fn max_hints(word: &str) -> usize {
+    // always leave at least half of the letters hidden
+    word.chars().filter(|&c| c != ' ').count() / 2
+}
 pub fn close_guess_levenshtein(word: &str) -> usize {
     match word.len() {
         0..=4 => 1,
@@ -1,10 +1,9 @@
 use crate::config::{CANVAS_HEIGHT, CANVAS_WIDTH};
 use serde::{Deserialize, Serialize};
 use std::alloc;
-use std::collections::hash_map::DefaultHasher;
+use std::borrow::Borrow;
 use std::convert::Infallible;
 use std::fmt;
-use std::hash::{Hash, Hasher};
 use std::marker::PhantomData;
 use std::mem;
 use std::num::NonZeroUsize;
@@ -27,12 +26,23 @@ impl Nickname {
     }
 
     pub fn user_id(&self) -> UserId {
-        let mut s = DefaultHasher::new();
-        self.0.hash(&mut s);
-        UserId(s.finish())
+        // FNV-1a, which unlike `DefaultHasher` is guaranteed to be stable
+        let hash = self.0.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+        });
+        UserId(hash)
     }
 }
 
+#[test]
+fn user_id_stable() {
+    assert_eq!(Nickname::new("").user_id(), UserId(0xcbf2_9ce4_8422_2325));
+    assert_eq!(
+        Nickname::new("ferris").user_id(),
+        UserId(0xfcf5_d08d_134a_6988)
+    );
+}
+
 impl Deref for Nickname {
     type Target = str;
 
@@ -55,6 +65,23 @@ impl fmt::Display for Nickname {
     }
 }
 
+/// Issued by the server to the first connection using a nickname, and required to reconnect as it.
+#[derive(Deserialize, Serialize, Copy, Clone, PartialEq, Eq)]
+pub struct SessionSecret([u8; 16]);
+
+impl SessionSecret {
+    pub fn new(bytes: [u8; 16]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl fmt::Debug for SessionSecret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // avoid leaking secrets into logs
+        f.write_str("SessionSecret(..)")
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone, PartialOrd, Ord, PartialEq, Eq)]
 pub struct Lobby(Arc<str>);
 
@@ -141,6 +168,12 @@ impl Default for Lowercase {
     }
 }
 
+impl Borrow<str> for Lowercase {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
 impl Deref for Lowercase {
     type Target = str;
 
@@ -160,6 +193,7 @@ pub enum Guess {
     System(Arc<str>),
     Help,
     Message(UserId, Lowercase),
+    NowHost(UserId),
     NowChoosing(UserId),
     NowDrawing(UserId),
     Guess(UserId, Lowercase),
@@ -173,6 +207,13 @@ pub enum Guess {
         user_id: UserId,
         score: u32,
     },
+    VoteKick {
+        voter: UserId,
+        target: UserId,
+        votes: u32,
+        needed: u32,
+    },
+    Kicked(Nickname),
 }
 
 #[test]
@@ -263,6 +304,62 @@ impl Tool {
     ];
 }
 
+#[derive(Debug, Deserialize, Serialize, Copy, Clone, PartialOrd, Ord, PartialEq, Eq)]
+pub enum WordPack {
+    Animals,
+    Food,
+    People,
+    Places,
+    Nature,
+    Sports,
+    Vehicles,
+    Music,
+    Clothing,
+    Technology,
+    Actions,
+    Objects,
+}
+
+impl WordPack {
+    pub const ALL: [Self; 12] = [
+        Self::Animals,
+        Self::Food,
+        Self::People,
+        Self::Places,
+        Self::Nature,
+        Self::Sports,
+        Self::Vehicles,
+        Self::Music,
+        Self::Clothing,
+        Self::Technology,
+        Self::Actions,
+        Self::Objects,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Animals => "Animals",
+            Self::Food => "Food",
+            Self::People => "People",
+            Self::Places => "Places",
+            Self::Nature => "Nature",
+            Self::Sports => "Sports & Games",
+            Self::Vehicles => "Vehicles",
+            Self::Music => "Music",
+            Self::Clothing => "Clothing",
+            Self::Technology => "Technology",
+            Self::Actions => "Actions & Ideas",
+            Self::Objects => "Objects",
+        }
+    }
+}
+
+impl fmt::Display for WordPack {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, Copy, Clone, PartialOrd, Ord, PartialEq, Eq)]
 pub struct I12Pair {
     bytes: [u8; 3],
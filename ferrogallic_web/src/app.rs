@@ -3,6 +3,7 @@ use crate::page;
 use crate::route::AppRoute;
 use crate::util::ArcPtrEq;
 use anyhow::Error;
+use ferrogallic_shared::api::game::Role;
 use std::convert::identity;
 use yew::{html, Callback, Component, Context, Html};
 use yew_router::router::BrowserRouter;
@@ -46,9 +47,14 @@ impl Component for App {
         let app_link = self.link.clone();
         let render_app = move |route| match route {
             AppRoute::Create => html! {<page::Create app_link={app_link.clone()}/>},
-            AppRoute::ChooseName { lobby } => html! {<page::ChooseName lobby={lobby.0.clone()}/>},
+            AppRoute::ChooseName { lobby } => {
+                html! {<page::ChooseName app_link={app_link.clone()} lobby={lobby.0.clone()}/>}
+            }
             AppRoute::InGame { lobby, nick } => {
-                html! {<page::InGame app_link={app_link.clone()} lobby={lobby.0.clone()} nick={nick.0.clone()}/>}
+                html! {<page::InGame app_link={app_link.clone()} lobby={lobby.0.clone()} nick={nick.0.clone()} role={Role::Player}/>}
+            }
+            AppRoute::Spectate { lobby, nick } => {
+                html! {<page::InGame app_link={app_link.clone()} lobby={lobby.0.clone()} nick={nick.0.clone()} role={Role::Spectator}/>}
             }
         };
         html! {
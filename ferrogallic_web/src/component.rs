@@ -4,7 +4,10 @@ pub mod error_popup;
 pub mod guess_area;
 pub mod guess_input;
 pub mod guess_template;
+pub mod paused_popup;
 pub mod players;
+pub mod round_summary_popup;
+pub mod settings;
 pub mod timer;
 pub mod tool_toolbar;
 
@@ -14,6 +17,9 @@ pub use error_popup::ErrorPopup;
 pub use guess_area::GuessArea;
 pub use guess_input::GuessInput;
 pub use guess_template::GuessTemplate;
+pub use paused_popup::PausedPopup;
 pub use players::Players;
+pub use round_summary_popup::RoundSummaryPopup;
+pub use settings::Settings;
 pub use timer::Timer;
 pub use tool_toolbar::ToolToolbar;
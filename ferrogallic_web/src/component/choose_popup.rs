@@ -1,6 +1,8 @@
+use crate::component;
 use crate::page;
 use crate::util::ArcPtrEq;
 use ferrogallic_shared::domain::Lowercase;
+use time::{Duration, OffsetDateTime};
 use yew::{html, Callback, Component, Context, Html, Properties};
 
 pub enum Msg {}
@@ -9,6 +11,8 @@ pub enum Msg {}
 pub struct Props {
     pub game_link: Callback<page::in_game::Msg>,
     pub words: ArcPtrEq<[Lowercase]>,
+    pub deadline: OffsetDateTime,
+    pub clock_offset: Duration,
 }
 
 pub struct ChoosePopup {}
@@ -45,7 +49,11 @@ impl Component for ChoosePopup {
             <dialog open=true class="hatched-background">
                 <div class="window">
                     <div class="title-bar">
-                        <div class="title-bar-text">{"Choose Word"}</div>
+                        <div class="title-bar-text">
+                            {"Choose Word ("}
+                            <component::Timer deadline={ctx.props().deadline} clock_offset={ctx.props().clock_offset} paused={None}/>
+                            {" seconds left)"}
+                        </div>
                     </div>
                     <div class="window-body">
                         <section class="field-row" style="justify-content: flex-end">
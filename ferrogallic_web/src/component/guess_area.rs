@@ -98,13 +98,15 @@ mod guess {
                 Guess::Help => html! {
                     <>
                     <li>{"❓ Type 'start' to start the game."}</li>
-                    <li>{"❓ Type 'rounds <number>' to change number of rounds."}</li>
-                    <li>{"❓ Type 'seconds <number>' to change guess timer."}</li>
+                    <li>{"❓ The host can change game settings in the panel below the player list."}</li>
                     </>
                 },
                 Guess::Message(user_id, message) => html! {
                     <li>{nickname(*user_id)}{": "}{message}</li>
                 },
+                Guess::NowHost(user_id) => html! {
+                    <li>{"👑 "}{nickname(*user_id)}{" is now the host."}</li>
+                },
                 Guess::NowChoosing(user_id) => html! {
                     <li>{"✨ "}{nickname(*user_id)}{" is choosing a word."}</li>
                 },
@@ -136,6 +138,17 @@ mod guess {
                 } => html! {
                     <li>{rank_emoji(*rank)}{" (#"}{rank}{") "}{nickname(*user_id)}{" with "}{score}{" points."}</li>
                 },
+                Guess::VoteKick {
+                    voter,
+                    target,
+                    votes,
+                    needed,
+                } => html! {
+                    <li>{"🗳️ "}{nickname(*voter)}{" voted to kick "}{nickname(*target)}{" ("}{votes}{"/"}{needed}{")."}</li>
+                },
+                Guess::Kicked(nick) => html! {
+                    <li>{"👢 "}{nick}{" was voted out."}</li>
+                },
             }
         }
     }
@@ -10,6 +10,7 @@ pub enum Msg {}
 pub struct Props {
     pub game_link: Callback<page::in_game::Msg>,
     pub guess: Lowercase,
+    pub disabled: bool,
 }
 
 pub struct GuessInput {}
@@ -40,6 +41,7 @@ impl Component for GuessInput {
                     type="text"
                     oninput={on_change_guess}
                     value={ctx.props().guess.to_string()}
+                    disabled={ctx.props().disabled}
                     style="width: 100%"
                 />
             </form>
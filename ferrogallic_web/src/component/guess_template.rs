@@ -1,3 +1,4 @@
+use ferrogallic_shared::api::game::GamePhase;
 use ferrogallic_shared::domain::Lowercase;
 use itertools::{EitherOrBoth, Itertools};
 use yew::{classes, html, Component, Context, Html, Properties};
@@ -17,6 +18,7 @@ pub struct GuessTemplate {}
 pub enum Reveal {
     All,
     Spaces,
+    Partial,
 }
 
 impl Component for GuessTemplate {
@@ -43,6 +45,11 @@ impl Component for GuessTemplate {
                 ' ' => Template::Space,
                 _ => Template::NonSpace,
             },
+            Reveal::Partial => |c| match c {
+                ' ' => Template::Space,
+                GamePhase::HIDDEN_CHAR => Template::NonSpace,
+                _ => Template::Exact(c),
+            },
         };
 
         let template_chars = ctx.props().word.chars().map(reveal_chars);
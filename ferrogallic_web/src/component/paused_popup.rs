@@ -0,0 +1,59 @@
+use crate::page;
+use ferrogallic_shared::api::game::PauseReason;
+use yew::{html, Callback, Component, Context, Html, Properties};
+
+pub enum Msg {}
+
+#[derive(PartialEq, Properties)]
+pub struct Props {
+    pub game_link: Callback<page::in_game::Msg>,
+    pub is_host: bool,
+    pub reason: PauseReason,
+}
+
+pub struct PausedPopup {}
+
+impl Component for PausedPopup {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {}
+    }
+
+    fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {}
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let on_resume = ctx
+            .props()
+            .game_link
+            .reform(|_| page::in_game::Msg::SetPaused(false));
+
+        html! {
+            <dialog open=true class="hatched-background">
+                <div class="window">
+                    <div class="title-bar">
+                        <div class="title-bar-text">{"Paused"}</div>
+                    </div>
+                    <div class="window-body">
+                        {match (ctx.props().reason, ctx.props().is_host) {
+                            (PauseReason::Host, true) => html! {
+                                <section class="field-row" style="justify-content: flex-end">
+                                    <button onclick={on_resume}>{"Resume"}</button>
+                                </section>
+                            },
+                            (PauseReason::Host, false) => html! {
+                                <p>{"Waiting for the host to resume the game..."}</p>
+                            },
+                            (PauseReason::DrawerDisconnected, _) => html! {
+                                <p>{"Waiting for the drawer to reconnect..."}</p>
+                            },
+                        }}
+                    </div>
+                </div>
+            </dialog>
+        }
+    }
+}
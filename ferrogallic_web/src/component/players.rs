@@ -1,5 +1,5 @@
 use crate::page;
-use ferrogallic_shared::api::game::{Player, PlayerStatus};
+use ferrogallic_shared::api::game::{Player, PlayerStatus, Role};
 use ferrogallic_shared::domain::UserId;
 use std::collections::BTreeMap;
 use std::sync::Arc;
@@ -10,7 +10,10 @@ pub enum Msg {}
 #[derive(PartialEq, Properties)]
 pub struct Props {
     pub game_link: Callback<page::in_game::Msg>,
+    pub user_id: UserId,
+    pub host: Option<UserId>,
     pub players: Arc<BTreeMap<UserId, Player>>,
+    pub queue: Vec<UserId>,
 }
 
 pub struct Players {}
@@ -28,51 +31,132 @@ impl Component for Players {
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
-        let player_rankings = Player::rankings(ctx.props().players.as_ref())
-            .take_while(|(_, _, player)| player.score > 0)
-            .map(|(rank, uid, _)| (uid, rank))
-            .collect::<BTreeMap<_, _>>();
-        let players = ctx
+        let (playing, spectating): (Vec<_>, Vec<_>) = ctx
             .props()
             .players
             .iter()
-            .map(|(&user_id, player)| {
-                let ranking = match player_rankings.get(&user_id) {
-                    Some(rank) => html! { <>{" (#"}{rank}{")"}</> },
-                    None => html! {},
-                };
-                let status = match player.status {
-                    PlayerStatus::Connected => html! { "connected" },
-                    PlayerStatus::Disconnected => {
-                        let epoch = player.epoch;
-                        let on_remove = ctx.props().game_link.reform(move |e: MouseEvent| {
-                            e.prevent_default();
-                            page::in_game::Msg::RemovePlayer(user_id, epoch)
-                        });
-                        html! {
-                            <>
-                                {"disconnected "}
-                                <a href="#" onclick={on_remove}>{"(remove)"}</a>
-                            </>
-                        }
+            .filter(|(_, player)| player.role != Role::Queued)
+            .partition(|(_, player)| player.role == Role::Player);
+        let queued = ctx
+            .props()
+            .queue
+            .iter()
+            .filter_map(|uid| ctx.props().players.get_key_value(uid))
+            .collect::<Vec<_>>();
+        let player_rankings = Player::rankings(playing.iter().copied())
+            .take_while(|(_, _, player)| player.score > 0)
+            .map(|(rank, uid, _)| (uid, rank))
+            .collect::<BTreeMap<_, _>>();
+        let is_host = ctx.props().host == Some(ctx.props().user_id);
+        let entry = |(&user_id, player): (&UserId, &Player)| {
+            let player_is_host = ctx.props().host == Some(user_id);
+            let host = match player_is_host {
+                true => html! { {" 👑"} },
+                false => html! {},
+            };
+            let ranking = match player_rankings.get(&user_id) {
+                Some(rank) => html! { <>{" (#"}{rank}{")"}</> },
+                None => html! {},
+            };
+            let status = match player.status {
+                PlayerStatus::Connected if is_host && !player_is_host => {
+                    let on_make_host = ctx.props().game_link.reform(move |e: MouseEvent| {
+                        e.prevent_default();
+                        page::in_game::Msg::MakeHost(user_id)
+                    });
+                    html! {
+                        <>
+                            {"connected "}
+                            <a href="#" onclick={on_make_host}>{"(make host)"}</a>
+                        </>
+                    }
+                }
+                PlayerStatus::Connected => html! { "connected" },
+                PlayerStatus::Disconnected | PlayerStatus::Away if is_host => {
+                    let epoch = player.epoch;
+                    let on_remove = ctx.props().game_link.reform(move |e: MouseEvent| {
+                        e.prevent_default();
+                        page::in_game::Msg::RemovePlayer(user_id, epoch)
+                    });
+                    html! {
+                        <>
+                            {status_text(player.status)}{" "}
+                            <a href="#" onclick={on_remove}>{"(remove)"}</a>
+                        </>
                     }
-                };
-                html! {
-                    <li>
-                        {&player.nick}
+                }
+                PlayerStatus::Disconnected | PlayerStatus::Away => {
+                    html! { status_text(player.status) }
+                }
+            };
+            let vote_kick = match player.status {
+                PlayerStatus::Connected if user_id != ctx.props().user_id => {
+                    let on_vote_kick = ctx.props().game_link.reform(move |e: MouseEvent| {
+                        e.prevent_default();
+                        page::in_game::Msg::VoteKick(user_id)
+                    });
+                    html! { <li><a href="#" onclick={on_vote_kick}>{"Vote to kick"}</a></li> }
+                }
+                PlayerStatus::Connected | PlayerStatus::Disconnected | PlayerStatus::Away => {
+                    html! {}
+                }
+            };
+            let score = match player.role {
+                Role::Player => html! { <li>{"Score: "}{player.score}{ranking}</li> },
+                Role::Spectator | Role::Queued => html! {},
+            };
+            html! {
+                <li>
+                    {&player.nick}{host}
+                    <ul>
+                        {score}
+                        <li>{"Status: "}{status}</li>
+                        {vote_kick}
+                    </ul>
+                </li>
+            }
+        };
+        let waiting = match queued.is_empty() {
+            true => html! {},
+            false => html! {
+                <li>
+                    <details open=true>
+                        <summary>{"Waiting for a seat"}</summary>
                         <ul>
-                            <li>{"Score: "}{player.score}{ranking}</li>
-                            <li>{"Status: "}{status}</li>
+                            {for queued.into_iter().map(entry)}
                         </ul>
-                    </li>
-                }
-            })
-            .collect::<Html>();
+                    </details>
+                </li>
+            },
+        };
+        let spectators = match spectating.is_empty() {
+            true => html! {},
+            false => html! {
+                <li>
+                    <details open=true>
+                        <summary>{"Spectators"}</summary>
+                        <ul>
+                            {for spectating.into_iter().map(entry)}
+                        </ul>
+                    </details>
+                </li>
+            },
+        };
 
         html! {
             <ul class="tree-view" style="height: 100%; overflow-y: scroll">
-                {players}
+                {for playing.into_iter().map(entry)}
+                {waiting}
+                {spectators}
             </ul>
         }
     }
 }
+
+fn status_text(status: PlayerStatus) -> &'static str {
+    match status {
+        PlayerStatus::Connected => "connected",
+        PlayerStatus::Disconnected => "disconnected",
+        PlayerStatus::Away => "away (turns skipped)",
+    }
+}
@@ -0,0 +1,69 @@
+use ferrogallic_shared::api::game::Player;
+use ferrogallic_shared::domain::{Lowercase, UserId};
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use yew::{html, Component, Context, Html, Properties};
+
+pub enum Msg {}
+
+#[derive(PartialEq, Properties)]
+pub struct Props {
+    pub players: Arc<BTreeMap<UserId, Player>>,
+    pub drawing: UserId,
+    pub word: Lowercase,
+    pub correct: BTreeMap<UserId, u32>,
+    pub drawer_score: u32,
+}
+
+pub struct RoundSummaryPopup {}
+
+impl Component for RoundSummaryPopup {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(_ctx: &Context<Self>) -> Self {
+        Self {}
+    }
+
+    fn update(&mut self, _ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {}
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let nick = |user_id: &UserId| match ctx.props().players.get(user_id) {
+            Some(player) => player.nick.to_string(),
+            None => "(left)".to_string(),
+        };
+
+        let mut correct = ctx.props().correct.iter().collect::<Vec<_>>();
+        correct.sort_by_key(|&(_, &score)| Reverse(score));
+        let guessers = if correct.is_empty() {
+            html! { <li>{"Nobody guessed it."}</li> }
+        } else {
+            correct
+                .into_iter()
+                .map(|(user_id, score)| {
+                    html! { <li>{nick(user_id)}{" +"}{score}</li> }
+                })
+                .collect::<Html>()
+        };
+
+        html! {
+            // no hatched background, so the drawing stays visible around the popup
+            <dialog open=true style="background: transparent">
+                <div class="window" style="min-width: 300px">
+                    <div class="title-bar">
+                        <div class="title-bar-text">{"The word was '"}{ctx.props().word.as_str()}{"'"}</div>
+                    </div>
+                    <div class="window-body">
+                        <ul class="tree-view">
+                            <li>{nick(&ctx.props().drawing)}{" (drawing) +"}{ctx.props().drawer_score}</li>
+                            {guessers}
+                        </ul>
+                    </div>
+                </div>
+            </dialog>
+        }
+    }
+}
@@ -0,0 +1,309 @@
+use crate::dom::InputEventExt;
+use crate::page;
+use ferrogallic_shared::api::game::{validate_custom_words, validate_password, GameConfig};
+use ferrogallic_shared::config::{
+    VALID_CHOOSE_SECONDS, VALID_CUSTOM_WORDS_PERCENT, VALID_DRAWER_GRACE_SECONDS,
+    VALID_DRAW_IDLE_SECONDS, VALID_GUESS_SECONDS, VALID_HINTS, VALID_MAX_PLAYERS,
+    VALID_REMOVE_DISCONNECTED_SECONDS, VALID_ROUNDS, VALID_SHORTENED_SECONDS,
+    VALID_SHORTEN_AFTER_PERCENT, VALID_SKIP_DISCONNECTED_SECONDS,
+};
+use ferrogallic_shared::domain::{Lowercase, WordPack};
+use std::fmt::Display;
+use std::mem;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+use web_sys::{InputEvent, SubmitEvent};
+use yew::{html, Callback, Component, Context, Html, MouseEvent, Properties};
+
+pub enum Msg {
+    SetDraft(GameConfig),
+    Save,
+    SetWords(String),
+    SaveWords,
+    SetPassword(String),
+    Lock,
+}
+
+#[derive(PartialEq, Properties)]
+pub struct Props {
+    pub game_link: Callback<page::in_game::Msg>,
+    pub config: GameConfig,
+    pub custom_words: usize,
+    pub used_words: usize,
+    pub locked: bool,
+    pub editable: bool,
+}
+
+pub struct Settings {
+    draft: GameConfig,
+    words: String,
+    password: String,
+}
+
+impl Component for Settings {
+    type Message = Msg;
+    type Properties = Props;
+
+    fn create(ctx: &Context<Self>) -> Self {
+        Self {
+            draft: ctx.props().config.clone(),
+            words: String::new(),
+            password: String::new(),
+        }
+    }
+
+    fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
+        match msg {
+            Msg::SetDraft(draft) => {
+                self.draft = draft;
+                true
+            }
+            Msg::Save => {
+                ctx.props()
+                    .game_link
+                    .emit(page::in_game::Msg::Configure(Box::new(self.draft.clone())));
+                false
+            }
+            Msg::SetWords(words) => {
+                self.words = words;
+                true
+            }
+            Msg::SaveWords => {
+                if let Ok(words) = parse_words(&self.words) {
+                    ctx.props()
+                        .game_link
+                        .emit(page::in_game::Msg::SetWords(words.into()));
+                }
+                false
+            }
+            Msg::SetPassword(password) => {
+                self.password = password;
+                true
+            }
+            Msg::Lock => {
+                if validate_password(&self.password).is_ok() {
+                    let password = mem::take(&mut self.password);
+                    ctx.props()
+                        .game_link
+                        .emit(page::in_game::Msg::SetPassword(Some(password.into())));
+                }
+                true
+            }
+        }
+    }
+
+    fn changed(&mut self, ctx: &Context<Self>, old_props: &Self::Properties) -> bool {
+        if ctx.props().config != old_props.config {
+            self.draft = ctx.props().config.clone();
+        }
+        true
+    }
+
+    fn view(&self, ctx: &Context<Self>) -> Html {
+        let on_save = ctx.link().callback(|e: SubmitEvent| {
+            e.prevent_default();
+            Msg::Save
+        });
+        let on_change_words = ctx
+            .link()
+            .callback(|e: InputEvent| Msg::SetWords(e.target_value()));
+        let on_save_words = ctx.link().callback(|e: SubmitEvent| {
+            e.prevent_default();
+            Msg::SaveWords
+        });
+        let on_reset_history = ctx
+            .props()
+            .game_link
+            .reform(|_: MouseEvent| page::in_game::Msg::ResetWordHistory);
+        let on_change_password = ctx
+            .link()
+            .callback(|e: InputEvent| Msg::SetPassword(e.target_value()));
+        let on_lock = ctx.link().callback(|e: SubmitEvent| {
+            e.prevent_default();
+            Msg::Lock
+        });
+        let on_unlock = ctx
+            .props()
+            .game_link
+            .reform(|_: MouseEvent| page::in_game::Msg::SetPassword(None));
+        let validation = self.draft.validate();
+        let unchanged = self.draft == ctx.props().config;
+        let words = parse_words(&self.words);
+        html! {
+            <fieldset>
+                <legend>{"Settings"}</legend>
+                <form onsubmit={on_save}>
+                    {self.number_field(ctx, "rounds", "Rounds", VALID_ROUNDS, |c| &mut c.rounds)}
+                    {self.number_field(ctx, "guess-seconds", "Seconds to guess", VALID_GUESS_SECONDS, |c| &mut c.guess_seconds)}
+                    {self.number_field(ctx, "choose-seconds", "Seconds to choose", VALID_CHOOSE_SECONDS, |c| &mut c.choose_seconds)}
+                    {self.number_field(ctx, "shorten-after-percent", "% guessed before shortening", VALID_SHORTEN_AFTER_PERCENT, |c| &mut c.shorten_after_percent)}
+                    {self.number_field(ctx, "shortened-seconds", "Seconds left once shortened", VALID_SHORTENED_SECONDS, |c| &mut c.shortened_seconds)}
+                    {self.number_field(ctx, "draw-idle-seconds", "Seconds before idle drawer skipped", VALID_DRAW_IDLE_SECONDS, |c| &mut c.draw_idle_seconds)}
+                    {self.number_field(ctx, "drawer-grace-seconds", "Seconds to wait for drawer", VALID_DRAWER_GRACE_SECONDS, |c| &mut c.drawer_grace_seconds)}
+                    {self.number_field(ctx, "hints", "Letters revealed", VALID_HINTS, |c| &mut c.hints)}
+                    {self.number_field(ctx, "max-players", "Max players", VALID_MAX_PLAYERS, |c| &mut c.max_players)}
+                    {self.number_field(ctx, "skip-disconnected-seconds", "Seconds before skipping", VALID_SKIP_DISCONNECTED_SECONDS, |c| &mut c.skip_disconnected_seconds)}
+                    {self.number_field(ctx, "remove-disconnected-seconds", "Seconds before removing", VALID_REMOVE_DISCONNECTED_SECONDS, |c| &mut c.remove_disconnected_seconds)}
+                    {self.number_field(ctx, "custom-words-percent", "Custom word %", VALID_CUSTOM_WORDS_PERCENT, |c| &mut c.custom_words_percent)}
+                    <div style="display: grid; grid-template-columns: 1fr 1fr">
+                        {for WordPack::ALL.iter().map(|&pack| self.pack_field(ctx, pack))}
+                    </div>
+                    {self.public_field(ctx)}
+                    {match (&validation, ctx.props().editable) {
+                        (Err(e), true) => html! { <p>{e}</p> },
+                        (Ok(()), _) | (Err(_), false) => html! {},
+                    }}
+                    <section class="field-row" style="justify-content: flex-end">
+                        <button disabled={!ctx.props().editable || validation.is_err() || unchanged}>
+                            {"Save"}
+                        </button>
+                    </section>
+                </form>
+                <div class="field-row">
+                    <span style="flex: 1">{"Words used: "}{ctx.props().used_words}</span>
+                    <button
+                        disabled={!ctx.props().editable || ctx.props().used_words == 0}
+                        onclick={on_reset_history}
+                    >
+                        {"Forget"}
+                    </button>
+                </div>
+                <p>{"Custom words: "}{ctx.props().custom_words}</p>
+                {if ctx.props().editable {
+                    html! {
+                        <form onsubmit={on_save_words}>
+                            <textarea
+                                placeholder="One word or phrase per line"
+                                style="width: 100%; box-sizing: border-box; resize: vertical"
+                                rows="4"
+                                oninput={on_change_words}
+                                value={self.words.clone()}
+                            />
+                            {match &words {
+                                Err(e) => html! { <p>{e}</p> },
+                                Ok(_) => html! {},
+                            }}
+                            <section class="field-row" style="justify-content: flex-end">
+                                <button disabled={words.is_err()}>{"Replace Custom Words"}</button>
+                            </section>
+                        </form>
+                    }
+                } else {
+                    html! {}
+                }}
+                <p>{if ctx.props().locked { "Locked with a password" } else { "Anyone with the link can join" }}</p>
+                {if ctx.props().editable {
+                    html! {
+                        <form onsubmit={on_lock} class="field-row">
+                            <input
+                                type="password"
+                                placeholder="New password"
+                                style="flex: 1; min-width: 0"
+                                oninput={on_change_password}
+                                value={self.password.clone()}
+                            />
+                            <button disabled={validate_password(&self.password).is_err()}>{"Lock"}</button>
+                            <button type="button" disabled={!ctx.props().locked} onclick={on_unlock}>{"Unlock"}</button>
+                        </form>
+                    }
+                } else {
+                    html! {}
+                }}
+            </fieldset>
+        }
+    }
+}
+
+impl Settings {
+    fn pack_field(&self, ctx: &Context<Self>, pack: WordPack) -> Html {
+        let id = format!("pack-{:?}", pack);
+        let on_toggle = ctx.link().callback({
+            let draft = self.draft.clone();
+            move |_: MouseEvent| {
+                let mut draft = draft.clone();
+                if !draft.packs.remove(&pack) {
+                    draft.packs.insert(pack);
+                }
+                Msg::SetDraft(draft)
+            }
+        });
+        html! {
+            <div class="field-row">
+                <input
+                    id={id.clone()}
+                    type="checkbox"
+                    disabled={!ctx.props().editable}
+                    checked={self.draft.packs.contains(&pack)}
+                    onclick={on_toggle}
+                />
+                <label for={id}>{pack.name()}</label>
+            </div>
+        }
+    }
+
+    fn public_field(&self, ctx: &Context<Self>) -> Html {
+        let on_toggle = ctx.link().callback({
+            let draft = self.draft.clone();
+            move |_: MouseEvent| {
+                let mut draft = draft.clone();
+                draft.public = !draft.public;
+                Msg::SetDraft(draft)
+            }
+        });
+        html! {
+            <div class="field-row">
+                <input
+                    id="public"
+                    type="checkbox"
+                    disabled={!ctx.props().editable}
+                    checked={self.draft.public}
+                    onclick={on_toggle}
+                />
+                <label for="public">{"List in public games"}</label>
+            </div>
+        }
+    }
+
+    fn number_field<T: Copy + Display + FromStr + Default + 'static>(
+        &self,
+        ctx: &Context<Self>,
+        id: &'static str,
+        label: &'static str,
+        range: RangeInclusive<T>,
+        field: fn(&mut GameConfig) -> &mut T,
+    ) -> Html {
+        let on_input = ctx.link().callback({
+            let draft = self.draft.clone();
+            move |e: InputEvent| {
+                let mut draft = draft.clone();
+                *field(&mut draft) = e.target_value().parse().unwrap_or_default();
+                Msg::SetDraft(draft)
+            }
+        });
+        let value = *field(&mut self.draft.clone());
+        html! {
+            <div class="field-row">
+                <label for={id} style="flex: 1">{label}</label>
+                <input
+                    id={id}
+                    type="number"
+                    style="width: 60px"
+                    min={range.start().to_string()}
+                    max={range.end().to_string()}
+                    disabled={!ctx.props().editable}
+                    oninput={on_input}
+                    value={value.to_string()}
+                />
+            </div>
+        }
+    }
+}
+
+fn parse_words(text: &str) -> Result<Vec<Lowercase>, String> {
+    let words = text
+        .split(['\n', ','])
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|word| !word.is_empty())
+        .map(Lowercase::new);
+    validate_custom_words(words)
+}
@@ -1,5 +1,5 @@
+use crate::util::local_now;
 use gloo::timers::callback::Interval;
-use js_sys::Date;
 use time::Duration;
 use time::OffsetDateTime;
 use yew::{html, Component, Context, Html, Properties};
@@ -10,8 +10,11 @@ pub enum Msg {
 
 #[derive(Properties, PartialEq)]
 pub struct Props {
-    pub started: OffsetDateTime,
-    pub count_down_from: Duration,
+    pub deadline: OffsetDateTime,
+    /// How far ahead the server's clock is of this browser's.
+    pub clock_offset: Duration,
+    /// When the game was paused, which is where the countdown stays until it resumes.
+    pub paused: Option<OffsetDateTime>,
 }
 
 pub struct Timer {
@@ -36,10 +39,11 @@ impl Component for Timer {
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
-        let elapsed = Duration::milliseconds(
-            Date::now() as i64 - ctx.props().started.unix_timestamp() * 1000,
-        );
-        let time_left = ctx.props().count_down_from - elapsed;
+        let server_now = match ctx.props().paused {
+            Some(paused) => paused,
+            None => local_now() + ctx.props().clock_offset,
+        };
+        let time_left = ctx.props().deadline - server_now;
         let seconds_left = time_left.whole_seconds();
         html! {
             {seconds_left}
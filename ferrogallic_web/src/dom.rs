@@ -1,5 +1,5 @@
 use wasm_bindgen::JsCast;
-use web_sys::{HtmlInputElement, InputEvent};
+use web_sys::{HtmlInputElement, HtmlTextAreaElement, InputEvent};
 
 pub trait InputEventExt {
     fn target_value(&self) -> String;
@@ -7,7 +7,13 @@ pub trait InputEventExt {
 
 impl InputEventExt for InputEvent {
     fn target_value(&self) -> String {
-        let optional_value = || Some(self.target()?.dyn_into::<HtmlInputElement>().ok()?.value());
+        let optional_value = || {
+            let target = self.target()?;
+            match target.dyn_into::<HtmlInputElement>() {
+                Ok(input) => Some(input.value()),
+                Err(target) => Some(target.dyn_into::<HtmlTextAreaElement>().ok()?.value()),
+            }
+        };
         optional_value().unwrap_or_default()
     }
 }
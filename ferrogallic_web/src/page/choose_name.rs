@@ -1,22 +1,38 @@
+use crate::api::fetch_api;
+use crate::app;
 use crate::dom::InputEventExt;
+use crate::page::in_game::password_key;
 use crate::route::{AppRoute, UrlEncoded};
+use anyhow::Error;
+use ferrogallic_shared::api::game::validate_password;
+use ferrogallic_shared::api::lobby::LobbyInfo;
 use ferrogallic_shared::domain::{Lobby, Nickname};
+use gloo::storage::{SessionStorage, Storage};
+use wasm_bindgen_futures::spawn_local;
 use web_sys::SubmitEvent;
-use yew::{html, Component, Context, Html, InputEvent, Properties};
+use yew::{html, Callback, Component, Context, Html, InputEvent, MouseEvent, Properties};
 use yew_router::scope_ext::RouterScopeExt;
 
 pub enum Msg {
     SetNick(Nickname),
+    SetPassword(String),
+    ToggleSpectate,
+    SetInfo(LobbyInfo),
     GoToLobby,
+    SetGlobalError(Error),
 }
 
 #[derive(PartialEq, Properties)]
 pub struct Props {
+    pub app_link: Callback<app::Msg>,
     pub lobby: Lobby,
 }
 
 pub struct ChooseName {
     nick: Nickname,
+    password: String,
+    spectate: bool,
+    info: Option<LobbyInfo>,
 }
 
 impl Component for ChooseName {
@@ -26,6 +42,9 @@ impl Component for ChooseName {
     fn create(_ctx: &Context<Self>) -> Self {
         Self {
             nick: Nickname::new(""),
+            password: String::new(),
+            spectate: false,
+            info: None,
         }
     }
 
@@ -35,15 +54,52 @@ impl Component for ChooseName {
                 self.nick = nick;
                 true
             }
+            Msg::SetPassword(password) => {
+                self.password = password;
+                true
+            }
+            Msg::ToggleSpectate => {
+                self.spectate = !self.spectate;
+                true
+            }
+            Msg::SetInfo(info) => {
+                self.info = Some(info);
+                true
+            }
             Msg::GoToLobby => {
+                let key = password_key(&ctx.props().lobby);
+                if self.password.is_empty() {
+                    SessionStorage::delete(key);
+                } else if let Err(e) = SessionStorage::set(key, &self.password) {
+                    log::error!("Failed to save password: {:?}", e);
+                }
                 if let Some(navigator) = ctx.link().navigator() {
-                    navigator.push(&AppRoute::InGame {
-                        lobby: UrlEncoded(ctx.props().lobby.clone()),
-                        nick: UrlEncoded(self.nick.clone()),
+                    let lobby = UrlEncoded(ctx.props().lobby.clone());
+                    let nick = UrlEncoded(self.nick.clone());
+                    navigator.push(&match self.spectate {
+                        true => AppRoute::Spectate { lobby, nick },
+                        false => AppRoute::InGame { lobby, nick },
                     });
                 }
                 false
             }
+            Msg::SetGlobalError(e) => {
+                ctx.props().app_link.emit(app::Msg::SetError(e));
+                false
+            }
+        }
+    }
+
+    fn rendered(&mut self, ctx: &Context<Self>, first_render: bool) {
+        if first_render {
+            let link = ctx.link().clone();
+            let lobby = ctx.props().lobby.clone();
+            spawn_local(async move {
+                link.send_message(match fetch_api(&lobby).await {
+                    Ok(info) => Msg::SetInfo(info),
+                    Err(e) => Msg::SetGlobalError(e.context("Failed to fetch lobby info")),
+                });
+            });
         }
     }
 
@@ -51,10 +107,22 @@ impl Component for ChooseName {
         let on_change_nick = ctx
             .link()
             .callback(|e: InputEvent| Msg::SetNick(Nickname::new(e.target_value())));
+        let on_change_password = ctx
+            .link()
+            .callback(|e: InputEvent| Msg::SetPassword(e.target_value()));
+        let on_toggle_spectate = ctx.link().callback(|_: MouseEvent| Msg::ToggleSpectate);
         let on_join_game = ctx.link().callback(|e: SubmitEvent| {
             e.prevent_default();
             Msg::GoToLobby
         });
+        let password_label = match &self.info {
+            Some(LobbyInfo { locked: true }) => "Password",
+            Some(LobbyInfo { locked: false }) | None => "Password (optional, locks a new lobby)",
+        };
+        let password_ok = match &self.info {
+            Some(LobbyInfo { locked: true }) => validate_password(&self.password).is_ok(),
+            _ => self.password.is_empty() || validate_password(&self.password).is_ok(),
+        };
         html! {
             <div style="display: flex; justify-content: space-evenly; align-items: flex-start">
                 <main class="window" style="min-width: 300px">
@@ -72,8 +140,26 @@ impl Component for ChooseName {
                                     value={self.nick.to_string()}
                                 />
                             </p>
+                            <p class="field-row-stacked">
+                                <label for="password">{password_label}</label>
+                                <input
+                                    id="password"
+                                    type="password"
+                                    oninput={on_change_password}
+                                    value={self.password.clone()}
+                                />
+                            </p>
+                            <p class="field-row">
+                                <input
+                                    id="spectate"
+                                    type="checkbox"
+                                    checked={self.spectate}
+                                    onclick={on_toggle_spectate}
+                                />
+                                <label for="spectate">{"Join as a spectator"}</label>
+                            </p>
                             <section class="field-row" style="justify-content: flex-end">
-                                <button disabled={self.nick.is_empty()}>
+                                <button disabled={self.nick.is_empty() || !password_ok}>
                                     {"Join"}
                                 </button>
                             </section>
@@ -3,11 +3,13 @@ use crate::app;
 use crate::dom::InputEventExt;
 use crate::route::{AppRoute, UrlEncoded};
 use anyhow::Error;
-use ferrogallic_shared::api::lobby::RandomLobbyName;
+use ferrogallic_shared::api::lobby::{
+    LobbyPhase, LobbySummary, PublicLobbies, QuickPlay, RandomLobbyName,
+};
 use ferrogallic_shared::domain::Lobby;
 use wasm_bindgen_futures::spawn_local;
 use web_sys::{InputEvent, SubmitEvent};
-use yew::{html, Callback, Component, Context, Html, Properties};
+use yew::{html, Callback, Component, Context, Html, MouseEvent, Properties};
 use yew_router::scope_ext::RouterScopeExt;
 
 pub enum Msg {
@@ -15,6 +17,10 @@ pub enum Msg {
     SetGeneratedLobbyName(Lobby),
     GoToCustomLobby,
     GoToGeneratedLobby,
+    GoToLobby(Lobby),
+    RefreshPublicLobbies,
+    SetPublicLobbies(Vec<LobbySummary>),
+    QuickPlay,
     SetGlobalError(Error),
 }
 
@@ -26,6 +32,7 @@ pub struct Props {
 pub struct Create {
     custom_lobby_name: Lobby,
     generated_lobby_name: Lobby,
+    public_lobbies: Option<Vec<LobbySummary>>,
 }
 
 impl Component for Create {
@@ -36,6 +43,7 @@ impl Component for Create {
         Self {
             custom_lobby_name: Lobby::new(""),
             generated_lobby_name: Lobby::new(""),
+            public_lobbies: None,
         }
     }
 
@@ -65,6 +73,33 @@ impl Component for Create {
                 }
                 false
             }
+            Msg::GoToLobby(lobby) => {
+                if let Some(navigator) = ctx.link().navigator() {
+                    navigator.push(&AppRoute::ChooseName {
+                        lobby: UrlEncoded(lobby),
+                    });
+                }
+                false
+            }
+            Msg::RefreshPublicLobbies => {
+                self.public_lobbies = None;
+                fetch_public_lobbies(ctx);
+                true
+            }
+            Msg::SetPublicLobbies(lobbies) => {
+                self.public_lobbies = Some(lobbies);
+                true
+            }
+            Msg::QuickPlay => {
+                let link = ctx.link().clone();
+                spawn_local(async move {
+                    link.send_message(match fetch_api(&()).await {
+                        Ok(QuickPlay { lobby }) => Msg::GoToLobby(lobby),
+                        Err(e) => Msg::SetGlobalError(e.context("Failed to find a game")),
+                    });
+                });
+                false
+            }
             Msg::SetGlobalError(e) => {
                 ctx.props().app_link.emit(app::Msg::SetError(e));
                 false
@@ -81,6 +116,7 @@ impl Component for Create {
                     Err(e) => Msg::SetGlobalError(e.context("Failed to fetch lobby name")),
                 });
             });
+            fetch_public_lobbies(ctx);
         }
     }
 
@@ -96,6 +132,10 @@ impl Component for Create {
             e.prevent_default();
             Msg::GoToGeneratedLobby
         });
+        let on_refresh = ctx
+            .link()
+            .callback(|_: MouseEvent| Msg::RefreshPublicLobbies);
+        let on_quick_play = ctx.link().callback(|_: MouseEvent| Msg::QuickPlay);
         html! {
             <main style="display: flex; justify-content: space-evenly; align-items: flex-start">
                 <div class="window" style="min-width: 300px">
@@ -144,7 +184,74 @@ impl Component for Create {
                         </form>
                     </article>
                 </div>
+                <div class="window" style="min-width: 300px">
+                    <div class="title-bar">
+                        <div class="title-bar-text">{"Public Games"}</div>
+                    </div>
+                    <article class="window-body">
+                        {match &self.public_lobbies {
+                            None => html! { <p>{"Loading..."}</p> },
+                            Some(lobbies) if lobbies.is_empty() => html! { <p>{"No public games right now."}</p> },
+                            Some(lobbies) => html! {
+                                <div class="sunken-panel">
+                                    <table style="width: 100%">
+                                        <thead>
+                                            <tr>
+                                                <th>{"Lobby"}</th>
+                                                <th>{"Players"}</th>
+                                                <th>{"Status"}</th>
+                                                <th></th>
+                                            </tr>
+                                        </thead>
+                                        <tbody>
+                                            {for lobbies.iter().map(|summary| self.public_lobby_row(ctx, summary))}
+                                        </tbody>
+                                    </table>
+                                </div>
+                            },
+                        }}
+                        <section class="field-row" style="justify-content: flex-end">
+                            <button onclick={on_refresh}>{"Refresh"}</button>
+                            <button onclick={on_quick_play}>{"Quick Play"}</button>
+                        </section>
+                    </article>
+                </div>
             </main>
         }
     }
 }
+
+impl Create {
+    fn public_lobby_row(&self, ctx: &Context<Self>, summary: &LobbySummary) -> Html {
+        let on_join = ctx.link().callback({
+            let lobby = summary.lobby.clone();
+            move |_: MouseEvent| Msg::GoToLobby(lobby.clone())
+        });
+        html! {
+            <tr>
+                <td>
+                    {summary.lobby.to_string()}
+                    {if summary.locked { " (locked)" } else { "" }}
+                </td>
+                <td>{summary.players}{" / "}{summary.max_players}</td>
+                <td>
+                    {match summary.phase {
+                        LobbyPhase::WaitingToStart => "Waiting to start".to_string(),
+                        LobbyPhase::Playing { round, rounds } => format!("Round {} of {}", round, rounds),
+                    }}
+                </td>
+                <td><button onclick={on_join}>{"Join"}</button></td>
+            </tr>
+        }
+    }
+}
+
+fn fetch_public_lobbies(ctx: &Context<Create>) {
+    let link = ctx.link().clone();
+    spawn_local(async move {
+        link.send_message(match fetch_api(&()).await {
+            Ok(PublicLobbies { lobbies }) => Msg::SetPublicLobbies(lobbies),
+            Err(e) => Msg::SetGlobalError(e.context("Failed to fetch public games")),
+        });
+    });
+}
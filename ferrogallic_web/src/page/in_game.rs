@@ -3,14 +3,20 @@ use crate::app;
 use crate::audio::AudioService;
 use crate::canvas::VirtualCanvas;
 use crate::component;
+use crate::util::local_now;
 use anyhow::{anyhow, Error};
-use ferrogallic_shared::api::game::{Canvas, Game, GamePhase, GameReq, GameState, Player};
-use ferrogallic_shared::config::{CANVAS_HEIGHT, CANVAS_WIDTH};
+use ferrogallic_shared::api::game::{
+    Canvas, Game, GameConfig, GamePhase, GameReq, GameState, JoinReq, PauseReason, Paused, Player,
+    Role,
+};
+use ferrogallic_shared::config::{CANVAS_HEIGHT, CANVAS_WIDTH, NUMBER_OF_WORDS_TO_CHOOSE};
 use ferrogallic_shared::domain::{
-    Color, Epoch, Guess, I12Pair, LineWidth, Lobby, Lowercase, Nickname, Tool, UserId,
+    Color, Epoch, Guess, I12Pair, LineWidth, Lobby, Lowercase, Nickname, SessionSecret, Tool,
+    UserId, WordPack,
 };
 use gloo::events::{EventListener, EventListenerOptions};
 use gloo::render::{request_animation_frame, AnimationFrame};
+use gloo::storage::{LocalStorage, SessionStorage, Storage};
 use std::collections::BTreeMap;
 use std::convert::identity;
 use std::mem;
@@ -26,6 +32,13 @@ pub enum Msg {
     WebSocketError(Error),
     Message(Game),
     RemovePlayer(UserId, Epoch<UserId>),
+    MakeHost(UserId),
+    VoteKick(UserId),
+    Configure(Box<GameConfig>),
+    SetWords(Box<[Lowercase]>),
+    ResetWordHistory,
+    SetPassword(Option<Box<str>>),
+    SetPaused(bool),
     ChooseWord(Lowercase),
     Pointer(PointerAction),
     Undo,
@@ -48,6 +61,7 @@ pub struct Props {
     pub app_link: Callback<app::Msg>,
     pub lobby: Lobby,
     pub nick: Nickname,
+    pub role: Role,
 }
 
 pub struct InGame {
@@ -65,6 +79,8 @@ pub struct InGame {
     players: Arc<BTreeMap<UserId, Player>>,
     game: Arc<GameState>,
     guesses: Arc<Vec<Guess>>,
+    /// How far ahead the server's clock is of this browser's.
+    clock_offset: Duration,
 }
 
 struct CanvasState {
@@ -99,6 +115,7 @@ impl Component for InGame {
             players: Default::default(),
             game: Default::default(),
             guesses: Default::default(),
+            clock_offset: Duration::ZERO,
         }
     }
 
@@ -147,12 +164,64 @@ impl Component for InGame {
                     self.guesses = Default::default();
                     true
                 }
-                Game::Heartbeat => false,
+                Game::Heartbeat(sync) => match sync.offset(local_now()) {
+                    Some(offset) => {
+                        self.clock_offset = offset;
+                        true
+                    }
+                    None => {
+                        // echo the heartbeat so the server can answer with both clocks
+                        self.send_if_connected(ctx, &GameReq::Heartbeat(local_now()));
+                        false
+                    }
+                },
+                Game::Session(session) => {
+                    let key = session_key(&ctx.props().lobby, &ctx.props().nick);
+                    if let Err(e) = LocalStorage::set(key, session) {
+                        log::error!("Failed to save session: {:?}", e);
+                    }
+                    false
+                }
+                Game::Closed(reason) => {
+                    self.active_ws = None;
+                    ctx.props()
+                        .app_link
+                        .emit(app::Msg::SetError(anyhow!("{}", reason)));
+                    false
+                }
             },
             Msg::RemovePlayer(user_id, epoch) => {
                 self.send_if_connected(ctx, &GameReq::Remove(user_id, epoch));
                 false
             }
+            Msg::MakeHost(user_id) => {
+                self.send_if_connected(ctx, &GameReq::MakeHost(user_id));
+                false
+            }
+            Msg::VoteKick(user_id) => {
+                self.send_if_connected(ctx, &GameReq::VoteKick(user_id));
+                false
+            }
+            Msg::Configure(config) => {
+                self.send_if_connected(ctx, &GameReq::Configure(config));
+                false
+            }
+            Msg::SetWords(words) => {
+                self.send_if_connected(ctx, &GameReq::SetWords(words));
+                false
+            }
+            Msg::ResetWordHistory => {
+                self.send_if_connected(ctx, &GameReq::ResetWordHistory);
+                false
+            }
+            Msg::SetPassword(password) => {
+                self.send_if_connected(ctx, &GameReq::SetPassword(password));
+                false
+            }
+            Msg::SetPaused(paused) => {
+                self.send_if_connected(ctx, &GameReq::SetPaused(paused));
+                false
+            }
             Msg::ChooseWord(word) => {
                 self.send_if_connected(ctx, &GameReq::Choose(word));
                 false
@@ -284,14 +353,17 @@ impl Component for InGame {
             Waiting,
             Choosing(&'a Player),
             Drawing(&'a Player),
+            RoundOver,
         }
 
+        let is_host = self.game.host == Some(self.user_id);
         let mut can_draw = false;
         let mut choose_words = None;
         let mut cur_round = None;
         let mut status = Status::Waiting;
-        let mut drawing_started = None;
+        let mut drawing_deadline = None;
         let mut guess_template = None;
+        let mut round_summary = None;
         let _: () = match &self.game.phase {
             GamePhase::WaitingToStart => {
                 can_draw = true;
@@ -300,13 +372,16 @@ impl Component for InGame {
                 round,
                 choosing,
                 words,
+                epoch: _,
+                started,
             } => {
                 cur_round = Some(*round);
                 if let Some(player) = self.players.get(choosing) {
                     status = Status::Choosing(player);
                 }
                 if *choosing == self.user_id {
-                    choose_words = Some(words.clone());
+                    let choose_seconds = Duration::seconds(self.game.config.choose_seconds.into());
+                    choose_words = Some((words.clone(), *started + choose_seconds));
                 }
             }
             GamePhase::Drawing {
@@ -314,23 +389,56 @@ impl Component for InGame {
                 drawing,
                 correct: _,
                 word,
+                revealed,
                 epoch: _,
-                started,
+                started: _,
+                deadline,
+                next_hint: _,
+                last_drawn: _,
             } => {
                 cur_round = Some(*round);
                 if let Some(player) = self.players.get(drawing) {
                     status = Status::Drawing(player);
                 }
-                drawing_started = Some(*started);
+                drawing_deadline = Some(*deadline);
                 if *drawing == self.user_id {
                     can_draw = true;
                     guess_template = Some((word.clone(), component::guess_template::Reveal::All));
-                } else {
+                } else if revealed.is_empty() {
                     guess_template =
                         Some((word.clone(), component::guess_template::Reveal::Spaces));
+                } else {
+                    guess_template =
+                        Some((word.clone(), component::guess_template::Reveal::Partial));
                 }
             }
+            GamePhase::RoundSummary {
+                round,
+                drawing,
+                word,
+                correct,
+                drawer_score,
+                epoch: _,
+                started: _,
+            } => {
+                cur_round = Some(*round);
+                status = Status::RoundOver;
+                guess_template = Some((word.clone(), component::guess_template::Reveal::All));
+                round_summary = Some(html! {
+                    <component::RoundSummaryPopup
+                        players={self.players.clone()}
+                        drawing={*drawing}
+                        word={word.clone()}
+                        correct={correct.clone()}
+                        drawer_score={*drawer_score}
+                    />
+                });
+            }
         };
+        if self.game.paused.is_some() {
+            can_draw = false;
+            choose_words = None;
+        }
 
         let on_keydown;
         let on_pointerdown;
@@ -383,11 +491,45 @@ impl Component for InGame {
         html! {
             <main class="window" style="max-width: 1500px; margin: auto">
                 <div class="title-bar">
-                    <div class="title-bar-text">{"In Game - "}{&ctx.props().lobby}</div>
+                    <div class="title-bar-text">{"In Game - "}{&ctx.props().lobby}{if self.game.locked { " (locked)" } else { "" }}</div>
                 </div>
                 <article class="window-body" style="display: flex">
-                    <section style="flex: 1; height: 804px">
-                        <component::Players game_link={self.link.clone()} players={self.players.clone()}/>
+                    <section style="flex: 1; height: 804px; display: flex; flex-direction: column">
+                        <div style="flex: 1; min-height: 0">
+                            <component::Players game_link={self.link.clone()} user_id={self.user_id} host={self.game.host} players={self.players.clone()} queue={self.game.queue.clone()}/>
+                        </div>
+                        {match &self.game.phase {
+                            GamePhase::WaitingToStart => html! {
+                                <div style="margin-top: 8px">
+                                    <component::Settings
+                                        game_link={self.link.clone()}
+                                        config={self.game.config.clone()}
+                                        custom_words={self.game.custom_words}
+                                        used_words={self.game.used_words}
+                                        locked={self.game.locked}
+                                        editable={is_host}
+                                    />
+                                </div>
+                            },
+                            GamePhase::ChoosingWords { .. }
+                            | GamePhase::Drawing { .. }
+                            | GamePhase::RoundSummary { .. } => {
+                                let host_paused = matches!(
+                                    self.game.paused,
+                                    Some(Paused { reason: PauseReason::Host, .. })
+                                );
+                                if is_host && !host_paused {
+                                    let on_pause = self.link.reform(|_| Msg::SetPaused(true));
+                                    html! {
+                                        <section class="field-row" style="margin-top: 8px; justify-content: flex-end">
+                                            <button onclick={on_pause}>{"Pause"}</button>
+                                        </section>
+                                    }
+                                } else {
+                                    html! {}
+                                }
+                            }
+                        }}
                     </section>
                     <section style="margin: 0 8px; position: relative" onkeydown={on_keydown}>
                         <fieldset style="padding-block-start: 2px; padding-block-end: 0px; padding-inline-start: 2px; padding-inline-end: 2px;">
@@ -410,15 +552,24 @@ impl Component for InGame {
                                 style={if can_draw { "" } else { "position: absolute; top: 0; width: 100%; height: 100%" }}
                             />
                         </div>
-                        {choose_words.map(|words| html! {
-                            <component::ChoosePopup game_link={self.link.clone()} words={words} />
+                        {round_summary.unwrap_or_default()}
+                        {self.game.paused.map(|paused| html! {
+                            <component::PausedPopup game_link={self.link.clone()} is_host={is_host} reason={paused.reason}/>
+                        }).unwrap_or_default()}
+                        {choose_words.map(|(words, deadline)| html! {
+                            <component::ChoosePopup
+                                game_link={self.link.clone()}
+                                words={words}
+                                deadline={deadline}
+                                clock_offset={self.clock_offset}
+                            />
                         }).unwrap_or_default()}
                     </section>
                     <section style="flex: 1; height: 804px; display: flex; flex-direction: column">
                         <div style="flex: 1; min-height: 0; margin-bottom: 8px">
                             <component::GuessArea players={self.players.clone()} guesses={self.guesses.clone()}/>
                         </div>
-                        <component::GuessInput game_link={self.link.clone()} guess={self.guess.clone()}/>
+                        <component::GuessInput game_link={self.link.clone()} guess={self.guess.clone()} disabled={self.game.paused.is_some()}/>
                     </section>
                 </article>
                 <footer class="status-bar">
@@ -427,11 +578,13 @@ impl Component for InGame {
                             Status::Waiting => html! { {"Waiting to start"} },
                             Status::Choosing(player) => html! { <>{&player.nick}{" is choosing a word"}</> },
                             Status::Drawing(player) => html! { <>{&player.nick}{" is drawing"}</> },
+                            Status::RoundOver => html! { {"Round over"} },
                         }}
                     </div>
+                    <div>{word_sources(&self.game)}</div>
                     <div>
-                        {drawing_started.map(|drawing_started| html! {
-                            <component::Timer started={drawing_started} count_down_from={Duration::seconds(i64::from(self.game.config.guess_seconds))}/>
+                        {drawing_deadline.map(|deadline| html! {
+                            <component::Timer deadline={deadline} clock_offset={self.clock_offset} paused={self.game.paused.map(|paused| paused.at)}/>
                          }).unwrap_or_default()}
                          {"/"}{self.game.config.guess_seconds}{" seconds"}
                     </div>
@@ -457,7 +610,21 @@ impl InGame {
         match connect_api() {
             Ok((mut reader, mut writer)) => {
                 let link = ctx.link().clone();
-                let join_rec = GameReq::Join(ctx.props().lobby.clone(), ctx.props().nick.clone());
+                let session = LocalStorage::get::<SessionSecret>(session_key(
+                    &ctx.props().lobby,
+                    &ctx.props().nick,
+                ))
+                .ok();
+                let password = SessionStorage::get::<String>(password_key(&ctx.props().lobby))
+                    .ok()
+                    .map(String::into_boxed_str);
+                let join_rec = GameReq::Join(Box::new(JoinReq {
+                    lobby: ctx.props().lobby.clone(),
+                    nick: ctx.props().nick.clone(),
+                    session,
+                    password,
+                    role: ctx.props().role,
+                }));
                 spawn_local(async move {
                     match writer.wait_for_connection_and_send(&join_rec).await {
                         Ok(()) => link.send_message(Msg::WebSocketConnected(writer)),
@@ -469,7 +636,12 @@ impl InGame {
                     loop {
                         match reader.next_api().await {
                             Some(Ok(msg)) => {
+                                // the server is about to close the connection, and has told us why
+                                let closed = matches!(msg, Game::Closed(_));
                                 link.send_message(Msg::Message(msg));
+                                if closed {
+                                    break;
+                                }
                             }
                             Some(Err(e)) => {
                                 link.send_message(Msg::WebSocketError(e));
@@ -561,3 +733,31 @@ impl InGame {
         }
     }
 }
+
+fn session_key(lobby: &Lobby, nick: &Nickname) -> String {
+    format!("session/{}/{}", lobby.to_ascii_lowercase(), nick)
+}
+
+pub fn password_key(lobby: &Lobby) -> String {
+    format!("password/{}", lobby.to_ascii_lowercase())
+}
+
+fn word_sources(game: &GameState) -> String {
+    let custom_percent = match game.custom_words {
+        0 => 0,
+        _ => game.config.custom_words_percent,
+    };
+    if custom_percent == 100 && game.custom_words >= NUMBER_OF_WORDS_TO_CHOOSE {
+        return "Custom words".to_string();
+    }
+    let mut sources = if game.config.packs.len() == WordPack::ALL.len() {
+        "All word packs".to_string()
+    } else {
+        let names = game.config.packs.iter().map(|pack| pack.name());
+        names.collect::<Vec<_>>().join(", ")
+    };
+    if custom_percent > 0 {
+        sources.push_str(" + custom words");
+    }
+    sources
+}
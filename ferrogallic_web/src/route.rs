@@ -12,6 +12,11 @@ pub enum AppRoute {
         lobby: UrlEncoded<Lobby>,
         nick: UrlEncoded<Nickname>,
     },
+    #[at("/join/:lobby/watch/:nick")]
+    Spectate {
+        lobby: UrlEncoded<Lobby>,
+        nick: UrlEncoded<Nickname>,
+    },
     #[at("/join/:lobby")]
     ChooseName { lobby: UrlEncoded<Lobby> },
     #[at("/create")]
@@ -1,5 +1,7 @@
+use js_sys::Date;
 use std::ops::{Deref, DerefMut};
 use std::sync::Arc;
+use time::OffsetDateTime;
 use yew::html::IntoPropValue;
 
 #[derive(Default)]
@@ -48,3 +50,9 @@ impl<T: ?Sized> DerefMut for ArcPtrEq<T> {
         &mut self.0
     }
 }
+
+/// The time according to this browser's clock, which may be well off from the server's.
+pub fn local_now() -> OffsetDateTime {
+    let nanos = Date::now() as i128 * 1_000_000;
+    OffsetDateTime::from_unix_timestamp_nanos(nanos).unwrap_or(OffsetDateTime::UNIX_EPOCH)
+}